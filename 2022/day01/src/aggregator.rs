@@ -0,0 +1,469 @@
+//! One-pass aggregation of the calories carried by every elf.
+
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fmt,
+    io::{self, BufRead},
+    str::FromStr,
+};
+
+use aoc_common::{ParseError, Result, Span};
+
+/// Integer type in which calories are counted.
+pub trait Total: Copy + Ord + FromStr + fmt::Display {
+    const ZERO: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    fn to_f64(self) -> f64;
+
+    /// Lossless widening, for sums over every elf.
+    fn to_i128(self) -> i128;
+}
+
+macro_rules! total {
+    ($($ty:ty),*) => {
+        $(
+            impl Total for $ty {
+                const ZERO: Self = 0;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$ty>::checked_add(self, other)
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+            }
+        )*
+    };
+}
+
+total!(i32, i64, u32, u64);
+
+/// Failure to read groups from a stream.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "cannot read input: {}", err),
+            Self::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(err: ParseError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+/// Elf of the leaderboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry<T> {
+    /// Position of the elf in the input, from 0
+    pub index: usize,
+    pub total: T,
+    /// Number of food items carried
+    pub items: usize,
+}
+
+/// Consumes lines one at a time, keeping the total of every elf and the
+/// `top` largest ones in a min-heap of bounded size, along with the elves
+/// tied with the smallest of them.
+///
+/// Only the lines are streamed: memory still grows with the number of elves,
+/// one total each, for the median and percentiles.
+pub struct Aggregator<T> {
+    top: usize,
+    /// Smallest kept total on top, the latest elf first on ties so that
+    /// earlier elves win them
+    heap: BinaryHeap<Reverse<(T, Reverse<usize>, usize)>>,
+    /// Elves left out of the heap with the same total as its smallest one
+    ties: Vec<Entry<T>>,
+    totals: Vec<T>,
+    /// Sum of every calorie, wide enough for any total of every elf to fit
+    sum: i128,
+    /// Total and item count of the group being read
+    current: Option<(T, usize)>,
+}
+
+impl<T: Total> Aggregator<T> {
+    pub fn new(top: usize) -> Self {
+        Self {
+            top,
+            heap: BinaryHeap::with_capacity(top + 1),
+            ties: vec![],
+            totals: vec![],
+            sum: 0,
+            current: None,
+        }
+    }
+
+    /// Adds a line of calories, a blank line closing the current group.
+    pub fn push(&mut self, line: &Span) -> Result<()> {
+        if line.is_empty() {
+            self.close();
+            return Ok(());
+        }
+
+        let calories = line.parse::<T>("a number of calories")?;
+        let overflow = || {
+            line.error(format!(
+                "calories summing up to a {}",
+                std::any::type_name::<T>()
+            ))
+        };
+        let (total, items) = self.current.unwrap_or((T::ZERO, 0));
+        self.current = Some((total.checked_add(calories).ok_or_else(overflow)?, items + 1));
+        self.sum += calories.to_i128();
+
+        Ok(())
+    }
+
+    fn close(&mut self) {
+        let Some((total, items)) = self.current.take() else {
+            return;
+        };
+        let index = self.totals.len();
+        self.totals.push(total);
+
+        self.heap.push(Reverse((total, Reverse(index), items)));
+        if self.heap.len() > self.top {
+            let Some(Reverse((total, Reverse(index), items))) = self.heap.pop() else {
+                return;
+            };
+            let cutoff = self.heap.peek().map(|Reverse((total, _, _))| *total);
+            self.ties.retain(|it| Some(it.total) == cutoff);
+            if Some(total) == cutoff {
+                self.ties.push(Entry {
+                    index,
+                    total,
+                    items,
+                });
+            }
+        }
+    }
+
+    pub fn finish(mut self) -> Summary<T> {
+        self.close();
+
+        let mut top = self
+            .heap
+            .into_iter()
+            .map(|Reverse((total, Reverse(index), items))| Entry {
+                index,
+                total,
+                items,
+            })
+            .chain(self.ties)
+            .collect::<Vec<_>>();
+        top.sort_by_key(|it| (Reverse(it.total), it.index));
+
+        let mut totals = self.totals;
+        totals.sort();
+
+        Summary {
+            totals,
+            top,
+            sum: self.sum,
+        }
+    }
+
+    /// Aggregates the groups of `reader` in a single pass.
+    ///
+    /// Like [`aoc_common::parser::lines`], `\r\n` endings and a byte order
+    /// mark are accepted.
+    pub fn read<R: BufRead>(
+        mut reader: R,
+        top: usize,
+    ) -> std::result::Result<Summary<T>, ReadError> {
+        let mut aggregator = Self::new(top);
+        let mut buf = String::new();
+
+        for line in 1.. {
+            buf.clear();
+            if reader.read_line(&mut buf)? == 0 {
+                break;
+            }
+            let mut text = buf.trim_end_matches('\n');
+            text = text.strip_suffix('\r').unwrap_or(text);
+            if line == 1 {
+                text = text.strip_prefix('\u{feff}').unwrap_or(text);
+            }
+            aggregator.push(&Span::new(line, 1, text))?;
+        }
+
+        Ok(aggregator.finish())
+    }
+}
+
+/// Totals of every elf, with the largest ones and a few statistics.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary<T> {
+    /// Total of every elf, in increasing order
+    pub totals: Vec<T>,
+    /// Elves carrying the most, largest total first then by index, with every
+    /// elf tied with the last one kept even beyond the requested count
+    pub top: Vec<Entry<T>>,
+    sum: i128,
+}
+
+impl<T: Total> Summary<T> {
+    /// Sum of every calorie of every elf.
+    pub fn sum(&self) -> i128 {
+        self.sum
+    }
+
+    /// Sum of the `count` largest totals, `None` when it overflows `T`.
+    pub fn top_total(&self, count: usize) -> Option<T> {
+        self.top
+            .iter()
+            .take(count)
+            .try_fold(T::ZERO, |acc, it| acc.checked_add(it.total))
+    }
+
+    /// The `count` elves carrying the most, at most as many as kept by the
+    /// aggregator, followed by the ones tied with the last of them.
+    pub fn leaderboard(&self, count: usize) -> Leaderboard<'_, T> {
+        let mut end = count.min(self.top.len());
+        while end > 0 && end < self.top.len() && self.top[end].total == self.top[end - 1].total {
+            end += 1;
+        }
+        Leaderboard(&self.top[..end])
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        (!self.totals.is_empty()).then(|| self.sum as f64 / self.totals.len() as f64)
+    }
+
+    /// Middle total, or the mean of the two middle ones.
+    pub fn median(&self) -> Option<f64> {
+        let len = self.totals.len();
+        match len {
+            0 => None,
+            _ if len % 2 == 1 => Some(self.totals[len / 2].to_f64()),
+            _ => Some((self.totals[len / 2 - 1].to_f64() + self.totals[len / 2].to_f64()) / 2.),
+        }
+    }
+
+    /// Smallest total such that `percent` % of the elves carry at most as
+    /// much (nearest-rank method).
+    pub fn percentile(&self, percent: f64) -> Option<T> {
+        let len = self.totals.len();
+        if len == 0 || !(0. ..=100.).contains(&percent) {
+            return None;
+        }
+        let rank = (percent / 100. * len as f64).ceil() as usize;
+        Some(self.totals[rank.clamp(1, len) - 1])
+    }
+}
+
+impl<T: Total> fmt::Display for Summary<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stat = |it: Option<f64>| it.map_or("-".into(), |it| format!("{:.1}", it));
+        let percentile = |it| self.percentile(it).map_or("-".into(), |it| it.to_string());
+        write!(
+            f,
+            "Elves: {}, mean: {}, median: {}, p90: {}, p99: {}",
+            self.totals.len(),
+            stat(self.mean()),
+            stat(self.median()),
+            percentile(90.),
+            percentile(99.)
+        )
+    }
+}
+
+/// Ranked elves, printed as a table.
+///
+/// Equal totals share their rank (`1, 2, 2, 4`) and are listed in input
+/// order. Elves are numbered from 1, like ranks.
+pub struct Leaderboard<'a, T>(pub &'a [Entry<T>]);
+
+impl<T: Total> Leaderboard<'_, T> {
+    /// Rank of every entry, equal totals sharing the best one.
+    pub fn ranks(&self) -> Vec<usize> {
+        let mut res: Vec<usize> = vec![];
+        for (idx, entry) in self.0.iter().enumerate() {
+            let rank = match idx {
+                0 => 1,
+                _ if self.0[idx - 1].total == entry.total => res[idx - 1],
+                _ => idx + 1,
+            };
+            res.push(rank);
+        }
+        res
+    }
+}
+
+impl<T: Total> fmt::Display for Leaderboard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, " Rank |   Elf |   Calories | Items")?;
+        write!(f, "\n------+-------+------------+------")?;
+        for (entry, rank) in self.0.iter().zip(self.ranks()) {
+            write!(
+                f,
+                "\n {:>4} | {:>5} | {:>10} | {:>5}",
+                rank,
+                entry.index + 1,
+                entry.total,
+                entry.items
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use aoc_common::{parser, ParseError};
+
+    use crate::aggregator::{Aggregator, Entry, ReadError, Summary};
+
+    fn aggregate(input: &str, top: usize) -> Summary<i64> {
+        let mut aggregator = Aggregator::new(top);
+        for line in parser::lines(input) {
+            aggregator.push(&line).unwrap();
+        }
+        aggregator.finish()
+    }
+
+    #[test]
+    fn top() {
+        let summary = aggregate("3\n\n1\n\n4\n\n1\n\n5\n\n9\n\n2\n\n6", 3);
+        assert_eq!(vec![1, 1, 2, 3, 4, 5, 6, 9], summary.totals);
+        assert_eq!(31, summary.sum());
+        let top = summary.top.iter().map(|it| (it.index, it.total));
+        assert_eq!(vec![(5, 9), (7, 6), (4, 5)], top.collect::<Vec<_>>());
+        assert_eq!(Some(20), summary.top_total(3));
+        assert_eq!(Some(9), summary.top_total(1));
+
+        // Elves tied with the last one kept are kept too
+        let summary = aggregate("1\n\n2\n\n2\n\n2", 2);
+        let top = summary.top.iter().map(|it| (it.index, it.total));
+        assert_eq!(vec![(1, 2), (2, 2), (3, 2)], top.collect::<Vec<_>>());
+        assert_eq!(Some(4), summary.top_total(2));
+
+        // Until a larger total pushes them out
+        let summary = aggregate("2\n\n2\n\n2\n\n3\n\n3", 2);
+        let top = summary.top.iter().map(|it| (it.index, it.total));
+        assert_eq!(vec![(3, 3), (4, 3)], top.collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn leaderboard() {
+        let summary = aggregate("5\n\n2\n2\n\n4\n\n1\n3\n\n1\n1\n1\n1", 5);
+        assert_eq!(
+            Entry {
+                index: 0,
+                total: 5,
+                items: 1
+            },
+            summary.top[0]
+        );
+        let leaderboard = summary.leaderboard(4);
+        assert_eq!(vec![1, 2, 2, 2, 2], leaderboard.ranks());
+        assert_eq!(
+            [
+                " Rank |   Elf |   Calories | Items",
+                "------+-------+------------+------",
+                "    1 |     1 |          5 |     1",
+                "    2 |     2 |          4 |     2",
+                "    2 |     3 |          4 |     1",
+                "    2 |     4 |          4 |     2",
+                "    2 |     5 |          4 |     4",
+            ]
+            .join("\n"),
+            leaderboard.to_string()
+        );
+        assert_eq!(5, summary.leaderboard(10).0.len());
+        assert_eq!(1, summary.leaderboard(1).0.len());
+    }
+
+    #[test]
+    fn cutoff_ties() {
+        // The 3rd and 4th elves tie at the cutoff of a top 3
+        let summary = aggregate("5\n\n3\n\n4\n\n3\n\n1", 3);
+        let leaderboard = summary.leaderboard(3);
+        let elves = leaderboard.0.iter().map(|it| it.index + 1);
+        assert_eq!(vec![1, 3, 2, 4], elves.collect::<Vec<_>>());
+        assert_eq!(vec![1, 2, 3, 3], leaderboard.ranks());
+        assert_eq!(Some(12), summary.top_total(3));
+        assert_eq!(2, summary.leaderboard(2).0.len());
+    }
+
+    #[test]
+    fn statistics() {
+        let summary = aggregate("3\n\n1\n\n4\n\n1\n\n5\n\n9\n\n2\n\n6", 1);
+        assert_eq!(Some(31. / 8.), summary.mean());
+        assert_eq!(Some(3.5), summary.median());
+        assert_eq!(Some(1), summary.percentile(0.));
+        assert_eq!(Some(3), summary.percentile(50.));
+        assert_eq!(Some(9), summary.percentile(90.));
+        assert_eq!(None, summary.percentile(101.));
+        assert_eq!(
+            "Elves: 8, mean: 3.9, median: 3.5, p90: 9, p99: 9",
+            summary.to_string()
+        );
+
+        let empty = aggregate("", 3);
+        assert!(empty.totals.is_empty());
+        assert_eq!(None, empty.median());
+        assert_eq!(Some(0), empty.top_total(3));
+    }
+
+    #[test]
+    fn stream() {
+        let reader = Cursor::new("\u{feff}1000\r\n2000\r\n\r\n\r\n4000\r\n\r\n");
+        let summary = Aggregator::<u64>::read(reader, 3).unwrap();
+        assert_eq!(vec![3000, 4000], summary.totals);
+
+        match Aggregator::<u64>::read(Cursor::new("1000\n\n-5"), 3) {
+            Err(ReadError::Parse(err)) => assert_eq!(
+                ParseError::new(3, 1, "a number of calories").found("-5"),
+                err
+            ),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn overflow() {
+        let max = u64::MAX.to_string();
+        let input = format!("{}\n1", max);
+        match Aggregator::<u64>::read(Cursor::new(input), 3) {
+            Err(ReadError::Parse(err)) => assert_eq!(
+                ParseError::new(2, 1, "calories summing up to a u64").found("1"),
+                err
+            ),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        // Every elf fits, only the sum over all of them does not
+        let summary = Aggregator::<u64>::read(Cursor::new(format!("1\n\n{}", max)), 3).unwrap();
+        assert_eq!(u64::MAX as i128 + 1, summary.sum());
+        assert_eq!(None, summary.top_total(2));
+
+        let summary = Aggregator::<u64>::read(Cursor::new(format!("{}\n1", u32::MAX)), 1);
+        assert_eq!(Some(u32::MAX as u64 + 1), summary.unwrap().top_total(1));
+    }
+}
@@ -0,0 +1,86 @@
+use aoc_common::{parser, Result, Solution};
+
+mod aggregator;
+
+pub use aggregator::{Aggregator, Entry, Leaderboard, ReadError, Summary, Total};
+
+/// Elves whose totals add up to the answer of part 2.
+pub const TOP: usize = 3;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Summary<u64>;
+    type Answer1 = u64;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut aggregator = Aggregator::new(TOP);
+        for line in parser::lines(input) {
+            aggregator.push(&line)?;
+        }
+        Ok(aggregator.finish())
+    }
+
+    fn part1(summary: &Self::Input) -> u64 {
+        summary.top_total(1).unwrap_or_default()
+    }
+
+    fn part2(summary: &Self::Input) -> u128 {
+        // Each total fits in a u64, but the sum of several of them may not
+        let top = summary.top.iter().take(TOP);
+        top.map(|it| it.total as u128).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day01;
+    use aoc_common::{input, ParseError, Solution};
+
+    #[test]
+    fn test1() {
+        let summary = Day01::parse(&input::raw("tests/test1.txt")).unwrap();
+        assert_eq!(vec![4000, 6000, 10000, 11000, 24000], summary.totals);
+        let top = summary.top.iter().map(|it| (it.index, it.total, it.items));
+        assert_eq!(
+            vec![(3, 24000, 3), (2, 11000, 2), (4, 10000, 1)],
+            top.collect::<Vec<_>>()
+        );
+        assert_eq!(Some(11000.), summary.mean());
+        assert_eq!(Some(10000.), summary.median());
+    }
+
+    #[test]
+    fn solution() {
+        let groups = Day01::parse(&input::raw("tests/test1.txt")).unwrap();
+        assert_eq!(24000, Day01::part1(&groups));
+        assert_eq!(45000, Day01::part2(&groups));
+    }
+
+    #[test]
+    fn line_endings() {
+        let expected = Day01::parse(&input::raw("tests/test1.txt")).unwrap();
+        for path in ["tests/test1_crlf.txt", "tests/test1_newline.txt"] {
+            let parsed = Day01::parse(&input::raw(path)).unwrap();
+            assert_eq!(Day01::part1(&expected), Day01::part1(&parsed));
+            assert_eq!(Day01::part2(&expected), Day01::part2(&parsed));
+        }
+    }
+
+    #[test]
+    fn large() {
+        let max = u64::MAX;
+        let groups = Day01::parse(&format!("{}\n\n{}\n\n1", max, max)).unwrap();
+        assert_eq!(max, Day01::part1(&groups));
+        assert_eq!(2 * max as u128 + 1, Day01::part2(&groups));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Err(ParseError::new(3, 1, "a number of calories").found("12O0")),
+            Day01::parse("1000\n\n12O0")
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use aoc2022_day01::{Aggregator, Day01, ReadError};
+use aoc_common::{
+    input::{Source, INPUT_VAR},
+    solution, Solution,
+};
+
+const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/myinput.txt");
+
+/// Removes `-l N` or `--leaderboard N` from `args`, returning the last `N`.
+fn leaderboard_size(args: &mut Vec<String>) -> Result<Option<usize>, String> {
+    let sizes = solution::take_options(args, "-l", "--leaderboard")?;
+    sizes
+        .last()
+        .map(|size| {
+            size.parse()
+                .map_err(|_| format!("invalid number of elves `{}`", size))
+        })
+        .transpose()
+}
+
+/// Prints the `size` elves carrying the most, reading the input as a stream.
+fn leaderboard(args: Vec<String>, size: usize) -> Result<(), String> {
+    let source = Source::resolve(
+        args.into_iter(),
+        std::env::var(INPUT_VAR).ok(),
+        DEFAULT_INPUT,
+    )?;
+    let summary = Aggregator::<u64>::read(source.open()?, size).map_err(|err| match err {
+        ReadError::Parse(err) => err.with_file(source.name()).to_string(),
+        ReadError::Io(err) => format!("cannot read input {}: {}", source.name(), err),
+    })?;
+    println!("{}", summary.leaderboard(size));
+    Ok(())
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    match leaderboard_size(&mut args) {
+        Ok(Some(size)) => {
+            return leaderboard(args, size).unwrap_or_else(|err| solution::fail(&err))
+        }
+        Ok(None) => {}
+        Err(err) => solution::fail(&err),
+    }
+
+    // The input is picked from the remaining arguments
+    let parsed = solution::load_from::<Day01, _>(args.into_iter(), DEFAULT_INPUT);
+    println!("Top calories: {}", Day01::part1(&parsed));
+    println!("Top 3 calories: {}", Day01::part2(&parsed));
+    println!("{}", parsed);
+}
@@ -0,0 +1,333 @@
+//! Strategy guide, and the ways to read its second column.
+
+use aoc_common::{parser, Result, Span};
+
+use crate::rules::{AResult, Rules, Sign};
+
+/// Letters used in the two columns of the guide.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Codes {
+    /// Sign of the opponent, in the order of the signs of the rules
+    pub opponent: Vec<String>,
+    pub column: Vec<String>,
+}
+
+impl Codes {
+    /// `A`, `B`, `C`… for the opponent and …`X`, `Y`, `Z` for the second
+    /// column, `count` of each, at most 13 as both columns share the alphabet.
+    pub fn letters(count: usize) -> std::result::Result<Self, String> {
+        if count > 13 {
+            return Err(format!("{} codes in each column, at most 13", count));
+        }
+        let letters = |from: u8| (from..from + count as u8).map(|it| (it as char).to_string());
+        Ok(Self {
+            opponent: letters(b'A').collect(),
+            column: letters(b'Z' + 1 - count as u8).collect(),
+        })
+    }
+}
+
+/// One line of the strategy guide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: Sign,
+    /// Index of the second column code, its meaning depends on the [`Mode`]
+    pub column: usize,
+}
+
+fn as_strs(codes: &[String]) -> Vec<&str> {
+    codes.iter().map(String::as_str).collect()
+}
+
+impl Round {
+    pub fn parse(line: &Span, codes: &Codes) -> Result<Self> {
+        let (opponent, column) = (as_strs(&codes.opponent), as_strs(&codes.column));
+
+        let opponent = line
+            .word(0, &parser::choices_list(&opponent))?
+            .one_of(&opponent)?;
+        let column = line
+            .word(1, &parser::choices_list(&column))?
+            .one_of(&column)?;
+        line.end_after(2)?;
+
+        Ok(Self {
+            opponent: Sign(opponent),
+            column,
+        })
+    }
+}
+
+pub fn parse_guide(input: &str, codes: &Codes) -> Result<Vec<Round>> {
+    parser::lines(input)
+        .map(|line| Round::parse(&line, codes))
+        .collect()
+}
+
+/// Meaning of the second column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mode {
+    /// The sign to play, in the order of the rules (part 1)
+    MySign,
+    /// The result to get: lose, draw or win (part 2)
+    Outcome,
+    /// The sign to play, for each code
+    Mapping(Vec<Sign>),
+}
+
+fn check_sign(rules: &Rules, sign: Sign) -> std::result::Result<Sign, String> {
+    match sign.0 < rules.len() {
+        true => Ok(sign),
+        false => Err(format!("sign {} is not part of the rules", sign.0)),
+    }
+}
+
+impl Mode {
+    /// Number of codes the second column can take, and what they stand for.
+    fn meanings(&self, rules: &Rules) -> (usize, &'static str) {
+        match self {
+            Self::MySign => (rules.len(), "signs"),
+            Self::Outcome => (AResult::ALL.len(), "results"),
+            Self::Mapping(signs) => (signs.len(), "mapped signs"),
+        }
+    }
+
+    /// Checks that every code of the second column has a meaning.
+    pub fn validate(&self, rules: &Rules, codes: &Codes) -> std::result::Result<(), String> {
+        if let Self::Mapping(signs) = self {
+            for sign in signs {
+                check_sign(rules, *sign)?;
+            }
+        }
+        let (expected, kind) = self.meanings(rules);
+        if codes.column.len() != expected {
+            return Err(format!(
+                "{} codes in the second column for {} {}",
+                codes.column.len(),
+                expected,
+                kind
+            ));
+        }
+        Ok(())
+    }
+
+    /// Sign to play in `round`, failing when the opponent sign is not part of
+    /// the rules or the second column code has no meaning.
+    pub fn my_sign(&self, rules: &Rules, round: &Round) -> std::result::Result<Sign, String> {
+        check_sign(rules, round.opponent)?;
+        let (expected, kind) = self.meanings(rules);
+        if round.column >= expected {
+            return Err(format!(
+                "code {} of the second column is beyond the {} {}",
+                round.column, expected, kind
+            ));
+        }
+        check_sign(
+            rules,
+            match self {
+                Self::MySign => Sign(round.column),
+                Self::Outcome => rules.sign_for(round.opponent, AResult::ALL[round.column]),
+                Self::Mapping(signs) => signs[round.column],
+            },
+        )
+    }
+}
+
+pub fn eval_round(rules: &Rules, mode: &Mode, round: &Round) -> std::result::Result<i32, String> {
+    Ok(rules.score(mode.my_sign(rules, round)?, round.opponent))
+}
+
+pub fn total_score(
+    rules: &Rules,
+    mode: &Mode,
+    rounds: &[Round],
+) -> std::result::Result<i32, String> {
+    rounds.iter().map(|it| eval_round(rules, mode, it)).sum()
+}
+
+/// Rearranges `items` into the next permutation in lexicographic order,
+/// returning false after the last one.
+fn next_permutation(items: &mut [usize]) -> bool {
+    let Some(pivot) = (1..items.len()).rev().find(|it| items[it - 1] < items[*it]) else {
+        return false;
+    };
+    let swap = (pivot..items.len())
+        .rev()
+        .find(|it| items[*it] > items[pivot - 1])
+        .unwrap();
+    items.swap(pivot - 1, swap);
+    items[pivot..].reverse();
+    true
+}
+
+/// Mapping of every code of the second column to a distinct sign scoring
+/// the most, the first one in lexicographic order on ties.
+///
+/// Fails when there are more codes than signs.
+pub fn best_mapping(
+    rules: &Rules,
+    codes: &Codes,
+    rounds: &[Round],
+) -> std::result::Result<(Vec<Sign>, i32), String> {
+    let count = codes.column.len();
+    if count > rules.len() {
+        return Err(format!(
+            "{} codes in the second column for {} signs",
+            count,
+            rules.len()
+        ));
+    }
+    let mut order = (0..rules.len()).collect::<Vec<_>>();
+    let mut best: Option<(Vec<Sign>, i32)> = None;
+
+    loop {
+        let signs = order[..count].iter().copied().map(Sign).collect::<Vec<_>>();
+        let score = total_score(rules, &Mode::Mapping(signs.clone()), rounds)?;
+        if best.as_ref().is_none_or(|(_, it)| score > *it) {
+            best = Some((signs, score));
+        }
+        // Signs left unmapped in decreasing order make the next permutation
+        // change the mapped ones
+        order[count..].reverse();
+        if !next_permutation(&mut order) {
+            break;
+        }
+    }
+
+    // There is always at least one mapping, even of no code
+    Ok(best.unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::ParseError;
+
+    use crate::{
+        guide::{best_mapping, next_permutation, parse_guide, total_score, Codes, Mode},
+        rules::{Rules, Sign},
+    };
+
+    #[test]
+    fn codes() {
+        assert_eq!(Codes::letters(3).unwrap().column, vec!["X", "Y", "Z"]);
+        let codes = Codes::letters(5).unwrap();
+        assert_eq!(codes.opponent, vec!["A", "B", "C", "D", "E"]);
+        assert_eq!(codes.column, vec!["V", "W", "X", "Y", "Z"]);
+        assert_eq!(Codes::letters(13).unwrap().column[0], "N");
+        assert_eq!(
+            Err("14 codes in each column, at most 13".into()),
+            Codes::letters(14)
+        );
+
+        let rounds = parse_guide("E V\nA Z", &codes).unwrap();
+        assert_eq!((Sign(4), 0), (rounds[0].opponent, rounds[0].column));
+        assert_eq!(
+            Err(ParseError::new(1, 3, "`V`, `W`, `X`, `Y` or `Z`").found("U")),
+            parse_guide("A U", &codes)
+        );
+    }
+
+    #[test]
+    fn modes() {
+        let rules = Rules::classic();
+        let codes = Codes::letters(3).unwrap();
+        let rounds = parse_guide("A Y\nB X\nC Z", &codes).unwrap();
+
+        assert_eq!(Ok(15), total_score(&rules, &Mode::MySign, &rounds));
+        assert_eq!(Ok(12), total_score(&rules, &Mode::Outcome, &rounds));
+        let mapping = Mode::Mapping(vec![Sign::SCISSORS, Sign::ROCK, Sign::PAPER]);
+        assert_eq!(mapping.validate(&rules, &codes), Ok(()));
+        // Rock vs Rock, Scissors vs Paper, Paper vs Scissors
+        assert_eq!(Ok(4 + 9 + 2), total_score(&rules, &mapping, &rounds));
+
+        let rpsls = Rules::lizard_spock();
+        assert_eq!(
+            Err("3 codes in the second column for 5 signs".into()),
+            Mode::MySign.validate(&rpsls, &codes)
+        );
+        assert_eq!(Ok(()), Mode::Outcome.validate(&rpsls, &codes));
+        assert!(Mode::Mapping(vec![Sign(5); 3])
+            .validate(&rpsls, &codes)
+            .is_err());
+
+        // Modes not matching the codes fail instead of panicking
+        assert_eq!(
+            Err("code 2 of the second column is beyond the 2 mapped signs".into()),
+            total_score(&rules, &Mode::Mapping(vec![Sign::ROCK; 2]), &rounds)
+        );
+        let wide_codes = Codes::letters(5).unwrap();
+        let wide = parse_guide("A Z\nE Z", &wide_codes).unwrap();
+        assert_eq!(
+            Err("code 4 of the second column is beyond the 3 results".into()),
+            total_score(&rpsls, &Mode::Outcome, &wide[..1])
+        );
+        assert_eq!(
+            Err("sign 4 is not part of the rules".into()),
+            total_score(&rules, &Mode::MySign, &wide[1..])
+        );
+        assert_eq!(
+            Err("5 codes in the second column for 3 signs".into()),
+            best_mapping(&rules, &wide_codes, &wide)
+        );
+    }
+
+    #[test]
+    fn permutations() {
+        let mut items = vec![0, 1, 2];
+        let mut all = vec![items.clone()];
+        while next_permutation(&mut items) {
+            all.push(items.clone());
+        }
+        assert_eq!(
+            vec![
+                vec![0, 1, 2],
+                vec![0, 2, 1],
+                vec![1, 0, 2],
+                vec![1, 2, 0],
+                vec![2, 0, 1],
+                vec![2, 1, 0]
+            ],
+            all
+        );
+    }
+
+    #[test]
+    fn best() {
+        let rules = Rules::classic();
+        let codes = Codes::letters(3).unwrap();
+        let rounds = parse_guide("A Y\nB X\nC Z", &codes).unwrap();
+        let (signs, score) = best_mapping(&rules, &codes, &rounds).unwrap();
+
+        assert_eq!(
+            Ok(score),
+            total_score(&rules, &Mode::Mapping(signs), &rounds)
+        );
+        let all = [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ];
+        for order in all {
+            let mode = Mode::Mapping(order.map(Sign).to_vec());
+            assert!(total_score(&rules, &mode, &rounds).unwrap() <= score);
+        }
+
+        // Fewer codes than signs map to distinct signs among all of them
+        let rpsls = Rules::lizard_spock();
+        let (signs, score) = best_mapping(&rpsls, &codes, &rounds).unwrap();
+        assert_eq!(3, signs.len());
+        let mut best = 0;
+        for (x, y, z) in
+            (0..5).flat_map(|x| (0..5).flat_map(move |y| (0..5).map(move |z| (x, y, z))))
+        {
+            if x != y && y != z && x != z {
+                let mode = Mode::Mapping(vec![Sign(x), Sign(y), Sign(z)]);
+                best = best.max(total_score(&rpsls, &mode, &rounds).unwrap());
+            }
+        }
+        assert_eq!(best, score);
+    }
+}
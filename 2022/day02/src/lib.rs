@@ -0,0 +1,91 @@
+use aoc_common::{Result, Solution};
+
+mod guide;
+mod rules;
+mod tournament;
+
+pub use guide::{best_mapping, eval_round, parse_guide, total_score, Codes, Mode, Round};
+pub use rules::{AResult, Rules, Sign};
+pub use tournament::{simulate, tournament, Report, Stats, Strategy};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Round>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_guide(input, &Codes::letters(3).expect("3 codes fit the alphabet"))
+    }
+
+    fn part1(rounds: &Self::Input) -> i32 {
+        // Parsing keeps to the 3 codes of each column
+        total_score(&Rules::classic(), &Mode::MySign, rounds).expect("rounds of the classic game")
+    }
+
+    fn part2(rounds: &Self::Input) -> i32 {
+        total_score(&Rules::classic(), &Mode::Outcome, rounds).expect("rounds of the classic game")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{eval_round, total_score, Day02, Mode, Rules};
+    use aoc_common::{input, ParseError, Solution};
+
+    #[test]
+    fn example() {
+        let rules = Rules::classic();
+        let rounds = Day02::parse(&input::raw("tests/test1.txt")).unwrap();
+        assert_eq!(Ok(8), eval_round(&rules, &Mode::MySign, &rounds[0]));
+        assert_eq!(Ok(1), eval_round(&rules, &Mode::MySign, &rounds[1]));
+        assert_eq!(Ok(6), eval_round(&rules, &Mode::MySign, &rounds[2]));
+
+        assert_eq!(Ok(15), total_score(&rules, &Mode::MySign, &rounds))
+    }
+
+    #[test]
+    fn example_part2() {
+        let rules = Rules::classic();
+        let rounds = Day02::parse(&input::raw("tests/test1.txt")).unwrap();
+        assert_eq!(Ok(4), eval_round(&rules, &Mode::Outcome, &rounds[0]));
+        assert_eq!(Ok(1), eval_round(&rules, &Mode::Outcome, &rounds[1]));
+        assert_eq!(Ok(7), eval_round(&rules, &Mode::Outcome, &rounds[2]));
+
+        assert_eq!(Ok(12), total_score(&rules, &Mode::Outcome, &rounds))
+    }
+
+    #[test]
+    fn solution() {
+        let rounds = Day02::parse(&input::raw("tests/test1.txt")).unwrap();
+        assert_eq!(15, Day02::part1(&rounds));
+        assert_eq!(12, Day02::part2(&rounds));
+    }
+
+    #[test]
+    fn line_endings() {
+        let expected = Day02::parse(&input::raw("tests/test1.txt")).unwrap();
+        for path in ["tests/test1_crlf.txt", "tests/test1_newline.txt"] {
+            let parsed = Day02::parse(&input::raw(path)).unwrap();
+            assert_eq!(Day02::part1(&expected), Day02::part1(&parsed));
+            assert_eq!(Day02::part2(&expected), Day02::part2(&parsed));
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Some(ParseError::new(2, 3, "`X`, `Y` or `Z`").found("W")),
+            Day02::parse("A Y\nB W").err()
+        );
+        assert_eq!(
+            Some(ParseError::new(1, 2, "`X`, `Y` or `Z`")),
+            Day02::parse("A").err()
+        );
+        assert_eq!(
+            Some(ParseError::new(1, 5, "end of line").found("Z")),
+            Day02::parse("A Y Z").err()
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use aoc2022_day02::{best_mapping, tournament, Codes, Day02, Mode, Report, Rules, Strategy};
+use aoc_common::{solution, Solution};
+
+fn main() {
+    let parsed = solution::load::<Day02>(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/myinput.txt"));
+    println!("Total score: {}", Day02::part1(&parsed));
+    println!("Total score (part2): {}", Day02::part2(&parsed));
+
+    let rules = Rules::classic();
+    // The codes the guide was parsed with
+    let codes = Codes::letters(3).unwrap_or_else(|err| solution::fail(&err));
+    let (signs, score) =
+        best_mapping(&rules, &codes, &parsed).unwrap_or_else(|err| solution::fail(&err));
+    let mapping = codes
+        .column
+        .iter()
+        .zip(signs)
+        .map(|(code, sign)| format!("{}={}", code, sign.name(&rules)))
+        .collect::<Vec<_>>();
+    println!("Best mapping: {} ({})", mapping.join(", "), score);
+
+    // The guide played as in part 2, against a few simple strategies
+    let guide = parsed
+        .iter()
+        .map(|it| Mode::Outcome.my_sign(&rules, it))
+        .collect::<Result<_, _>>()
+        .unwrap_or_else(|err| solution::fail(&err));
+    let strategies = [
+        Strategy::Guide(guide),
+        Strategy::Random(2022),
+        Strategy::CounterLast,
+        Strategy::Frequency,
+    ];
+    println!("{}", Report(tournament(&rules, &strategies, 10_000)));
+}
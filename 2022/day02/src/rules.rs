@@ -0,0 +1,265 @@
+//! Rules of rock-paper-scissors and of its variants with more signs.
+
+/// Sign played, as an index in the signs of the [`Rules`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Sign(pub usize);
+
+impl Sign {
+    pub const ROCK: Sign = Sign(0);
+    pub const PAPER: Sign = Sign(1);
+    pub const SCISSORS: Sign = Sign(2);
+
+    pub fn val(&self, rules: &Rules) -> i32 {
+        rules.scores[self.0]
+    }
+
+    pub fn name<'a>(&self, rules: &'a Rules) -> &'a str {
+        &rules.names[self.0]
+    }
+}
+
+/// Outcome of a round, for the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AResult {
+    Win,
+    Lose,
+    Draw,
+}
+
+impl AResult {
+    /// Results in the order of the `X`, `Y` and `Z` codes.
+    pub const ALL: [AResult; 3] = [Self::Lose, Self::Draw, Self::Win];
+
+    pub fn val(&self, rules: &Rules) -> i32 {
+        match self {
+            Self::Lose => rules.outcomes[0],
+            Self::Draw => rules.outcomes[1],
+            Self::Win => rules.outcomes[2],
+        }
+    }
+}
+
+/// Signs, their scores and who beats whom.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    names: Vec<String>,
+    scores: Vec<i32>,
+    /// Scores of a loss, a draw and a win
+    outcomes: [i32; 3],
+    /// `beats[a][b]` when sign `a` wins against sign `b`
+    beats: Vec<Vec<bool>>,
+}
+
+impl Rules {
+    /// Validates a dominance relation: no sign beats itself, every other
+    /// pair has exactly one winner, and every sign can be beaten (so that
+    /// [`Rules::sign_for`] always has an answer).
+    pub fn new(
+        names: &[&str],
+        scores: &[i32],
+        outcomes: [i32; 3],
+        beats: Vec<Vec<bool>>,
+    ) -> Result<Self, String> {
+        let len = names.len();
+        if len < 3 {
+            return Err(format!(
+                "{} signs are not enough, at least 3 are needed",
+                len
+            ));
+        }
+        if scores.len() != len || beats.len() != len || beats.iter().any(|it| it.len() != len) {
+            return Err(format!("scores and dominance must cover the {} signs", len));
+        }
+        for a in 0..len {
+            if beats[a][a] {
+                return Err(format!("{} cannot beat itself", names[a]));
+            }
+            for b in a + 1..len {
+                if beats[a][b] == beats[b][a] {
+                    return Err(format!(
+                        "exactly one of {} and {} must beat the other",
+                        names[a], names[b]
+                    ));
+                }
+            }
+            if !(0..len).any(|b| beats[b][a]) || !(0..len).any(|b| beats[a][b]) {
+                return Err(format!("{} must both beat and lose to some sign", names[a]));
+            }
+        }
+
+        Ok(Self {
+            names: names.iter().map(|it| it.to_string()).collect(),
+            scores: scores.to_vec(),
+            outcomes,
+            beats,
+        })
+    }
+
+    /// Balanced variant of an odd number of signs, each one beating the signs
+    /// at an odd distance before it in the cycle, and scoring its position
+    /// (from 1).
+    ///
+    /// Rock, Paper, Scissors, Spock, Lizard gives the usual RPSLS rules.
+    pub fn cyclic(names: &[&str]) -> Result<Self, String> {
+        let len = names.len();
+        if len.is_multiple_of(2) {
+            return Err(format!(
+                "{} signs cannot be balanced, the number of signs must be odd",
+                len
+            ));
+        }
+        let beats = (0..len)
+            .map(|a| (0..len).map(|b| (a + len - b) % len % 2 == 1).collect())
+            .collect();
+        let scores = (1..=len as i32).collect::<Vec<_>>();
+        Self::new(names, &scores, [0, 3, 6], beats)
+    }
+
+    /// Rock, paper, scissors, as played in the puzzle.
+    pub fn classic() -> Self {
+        Self::cyclic(&["Rock", "Paper", "Scissors"]).unwrap()
+    }
+
+    /// Rock, paper, scissors, Spock, lizard.
+    pub fn lizard_spock() -> Self {
+        Self::cyclic(&["Rock", "Paper", "Scissors", "Spock", "Lizard"]).unwrap()
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn signs(&self) -> impl Iterator<Item = Sign> {
+        (0..self.len()).map(Sign)
+    }
+
+    pub fn sign(&self, name: &str) -> Option<Sign> {
+        self.names
+            .iter()
+            .position(|it| it.eq_ignore_ascii_case(name))
+            .map(Sign)
+    }
+
+    pub fn beats(&self, a: Sign, b: Sign) -> bool {
+        self.beats[a.0][b.0]
+    }
+
+    /// Result of playing `me` against `opponent`.
+    pub fn outcome(&self, me: Sign, opponent: Sign) -> AResult {
+        if me == opponent {
+            AResult::Draw
+        } else if self.beats(me, opponent) {
+            AResult::Win
+        } else {
+            AResult::Lose
+        }
+    }
+
+    /// Sign to play against `opponent` to get `result`, the best scoring one
+    /// when several would do.
+    pub fn sign_for(&self, opponent: Sign, result: AResult) -> Sign {
+        self.signs()
+            .filter(|it| self.outcome(*it, opponent) == result)
+            .min_by_key(|it| (-it.val(self), it.0))
+            .expect("validated rules let every result happen")
+    }
+
+    /// Score of a round, for the player of `me`.
+    pub fn score(&self, me: Sign, opponent: Sign) -> i32 {
+        me.val(self) + self.outcome(me, opponent).val(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rules::{AResult, Rules, Sign};
+
+    #[test]
+    fn classic() {
+        let rules = Rules::classic();
+        assert_eq!(AResult::Win, rules.outcome(Sign::PAPER, Sign::ROCK));
+        assert_eq!(AResult::Win, rules.outcome(Sign::ROCK, Sign::SCISSORS));
+        assert_eq!(AResult::Lose, rules.outcome(Sign::ROCK, Sign::PAPER));
+        assert_eq!(AResult::Draw, rules.outcome(Sign::SCISSORS, Sign::SCISSORS));
+        assert_eq!(8, rules.score(Sign::PAPER, Sign::ROCK));
+        assert_eq!(Sign::ROCK, rules.sign_for(Sign::SCISSORS, AResult::Win));
+        assert_eq!(Sign::SCISSORS, rules.sign_for(Sign::ROCK, AResult::Lose));
+    }
+
+    #[test]
+    fn lizard_spock() {
+        let rules = Rules::lizard_spock();
+        let sign = |name| rules.sign(name).unwrap();
+        for (winner, loser) in [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ] {
+            assert_eq!(AResult::Win, rules.outcome(sign(winner), sign(loser)));
+            assert_eq!(AResult::Lose, rules.outcome(sign(loser), sign(winner)));
+        }
+        // Both Spock and Paper beat Rock, Spock scores more
+        assert_eq!(sign("Spock"), rules.sign_for(sign("Rock"), AResult::Win));
+    }
+
+    #[test]
+    fn any_odd_variant() {
+        let names = ["a", "b", "c", "d", "e", "f", "g"];
+        let rules = Rules::cyclic(&names).unwrap();
+        for me in rules.signs() {
+            let wins = rules.signs().filter(|it| rules.beats(me, *it)).count();
+            assert_eq!(3, wins);
+            for result in AResult::ALL {
+                let sign = rules.sign_for(me, result);
+                assert_eq!(result, rules.outcome(sign, me));
+            }
+        }
+
+        assert!(Rules::cyclic(&names[..4]).is_err());
+        assert!(Rules::cyclic(&names[..1]).is_err());
+    }
+
+    #[test]
+    fn validation() {
+        let beats = |rows: [[bool; 3]; 3]| rows.iter().map(|it| it.to_vec()).collect();
+        let names = ["a", "b", "c"];
+        assert_eq!(
+            Err("exactly one of a and b must beat the other".into()),
+            Rules::new(
+                &names,
+                &[1, 2, 3],
+                [0, 3, 6],
+                beats([
+                    [false, true, true],
+                    [true, false, true],
+                    [false, false, false]
+                ])
+            )
+        );
+        assert_eq!(
+            Err("a must both beat and lose to some sign".into()),
+            Rules::new(
+                &names,
+                &[1, 2, 3],
+                [0, 3, 6],
+                beats([
+                    [false, true, true],
+                    [false, false, true],
+                    [false, false, false]
+                ])
+            )
+        );
+        assert!(Rules::new(&names, &[1, 2], [0, 3, 6], beats([[false; 3]; 3])).is_err());
+    }
+}
@@ -0,0 +1,284 @@
+//! Simulated matches between playing strategies.
+
+use std::fmt;
+
+use crate::rules::{AResult, Rules, Sign};
+
+/// How a player picks its next sign.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Strategy {
+    /// Plays the signs of a guide in order, starting over at the end
+    Guide(Vec<Sign>),
+    /// Plays uniformly random signs, reproducible from the seed
+    Random(u64),
+    /// Plays what beats the last sign of the opponent
+    CounterLast,
+    /// Plays what beats the sign the opponent played the most
+    Frequency,
+}
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Guide(_) => write!(f, "guide"),
+            Self::Random(seed) => write!(f, "random({})", seed),
+            Self::CounterLast => write!(f, "counter-last"),
+            Self::Frequency => write!(f, "frequency"),
+        }
+    }
+}
+
+/// Sign played when there is nothing to go on yet.
+const OPENING: Sign = Sign(0);
+
+/// Strategy along with its state during a match.
+struct Player<'a> {
+    strategy: &'a Strategy,
+    /// State of the SplitMix64 generator of [`Strategy::Random`]
+    seed: u64,
+}
+
+impl<'a> Player<'a> {
+    fn new(strategy: &'a Strategy) -> Self {
+        let seed = match strategy {
+            Strategy::Random(seed) => *seed,
+            _ => 0,
+        };
+        Self { strategy, seed }
+    }
+
+    fn random(&mut self) -> u64 {
+        self.seed = self.seed.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.seed;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Next sign, knowing every sign the opponent played so far.
+    fn play(&mut self, rules: &Rules, opponent: &[Sign]) -> Sign {
+        match self.strategy {
+            Strategy::Guide(signs) if signs.is_empty() => OPENING,
+            Strategy::Guide(signs) => signs[opponent.len() % signs.len()],
+            Strategy::Random(_) => Sign((self.random() % rules.len() as u64) as usize),
+            Strategy::CounterLast => opponent
+                .last()
+                .map_or(OPENING, |it| rules.sign_for(*it, AResult::Win)),
+            Strategy::Frequency => {
+                let mut counts = vec![0; rules.len()];
+                for sign in opponent {
+                    counts[sign.0] += 1;
+                }
+                // Most played sign, the first one on ties
+                let favourite = rules
+                    .signs()
+                    .min_by_key(|it| std::cmp::Reverse(counts[it.0]));
+                match favourite {
+                    Some(sign) if !opponent.is_empty() => rules.sign_for(sign, AResult::Win),
+                    _ => OPENING,
+                }
+            }
+        }
+    }
+}
+
+/// Results of a match, for the first player.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub rounds: usize,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub score: i64,
+}
+
+impl Stats {
+    fn record(&mut self, rules: &Rules, me: Sign, opponent: Sign) {
+        let result = rules.outcome(me, opponent);
+        match result {
+            AResult::Win => self.wins += 1,
+            AResult::Draw => self.draws += 1,
+            AResult::Lose => self.losses += 1,
+        }
+        self.rounds += 1;
+        self.score += (me.val(rules) + result.val(rules)) as i64;
+    }
+
+    fn rate(&self, count: usize) -> f64 {
+        count as f64 / self.rounds.max(1) as f64
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        self.rate(self.wins)
+    }
+
+    pub fn draw_rate(&self) -> f64 {
+        self.rate(self.draws)
+    }
+
+    pub fn loss_rate(&self) -> f64 {
+        self.rate(self.losses)
+    }
+
+    /// Average score of a round.
+    pub fn expected_score(&self) -> f64 {
+        self.score as f64 / self.rounds.max(1) as f64
+    }
+}
+
+/// Plays `rounds` rounds of `first` against `second`.
+pub fn simulate(rules: &Rules, first: &Strategy, second: &Strategy, rounds: usize) -> Stats {
+    let mut players = (Player::new(first), Player::new(second));
+    let mut played: (Vec<Sign>, Vec<Sign>) = (vec![], vec![]);
+    let mut stats = Stats::default();
+
+    for _ in 0..rounds {
+        let me = players.0.play(rules, &played.1);
+        let opponent = players.1.play(rules, &played.0);
+        stats.record(rules, me, opponent);
+        played.0.push(me);
+        played.1.push(opponent);
+    }
+
+    stats
+}
+
+/// Every strategy against every other one, each pair playing once.
+pub fn tournament<'a>(
+    rules: &Rules,
+    strategies: &'a [Strategy],
+    rounds: usize,
+) -> Vec<(&'a Strategy, &'a Strategy, Stats)> {
+    let mut res = vec![];
+    for (idx, first) in strategies.iter().enumerate() {
+        for second in strategies[idx + 1..].iter() {
+            res.push((first, second, simulate(rules, first, second, rounds)));
+        }
+    }
+    res
+}
+
+/// Tournament results, printed as a table.
+pub struct Report<'a>(pub Vec<(&'a Strategy, &'a Strategy, Stats)>);
+
+impl fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = self
+            .0
+            .iter()
+            .map(|(first, second, _)| format!("{} vs {}", first, second))
+            .collect::<Vec<_>>();
+        let width = names
+            .iter()
+            .map(String::len)
+            .fold("Match".len(), usize::max);
+
+        write!(
+            f,
+            " {:<width$} |   Win |  Draw |  Loss | Expected score",
+            "Match"
+        )?;
+        write!(
+            f,
+            "\n-{}-+-------+-------+-------+---------------",
+            "-".repeat(width)
+        )?;
+        for (name, (_, _, stats)) in names.iter().zip(self.0.iter()) {
+            write!(
+                f,
+                "\n {:<width$} | {:>4.1}% | {:>4.1}% | {:>4.1}% | {:>14.2}",
+                name,
+                stats.win_rate() * 100.,
+                stats.draw_rate() * 100.,
+                stats.loss_rate() * 100.,
+                stats.expected_score()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        rules::{Rules, Sign},
+        tournament::{simulate, tournament, Report, Stats, Strategy},
+    };
+
+    #[test]
+    fn guides() {
+        // The example guide, read as in part 1
+        let rules = Rules::classic();
+        let opponent = Strategy::Guide(vec![Sign::ROCK, Sign::PAPER, Sign::SCISSORS]);
+        let me = Strategy::Guide(vec![Sign::PAPER, Sign::ROCK, Sign::SCISSORS]);
+
+        assert_eq!(
+            Stats {
+                rounds: 3,
+                wins: 1,
+                draws: 1,
+                losses: 1,
+                score: 15
+            },
+            simulate(&rules, &me, &opponent, 3)
+        );
+        assert_eq!(30, simulate(&rules, &me, &opponent, 6).score);
+    }
+
+    #[test]
+    fn counters() {
+        let rules = Rules::classic();
+        let rock = Strategy::Guide(vec![Sign::ROCK]);
+
+        // Only the opening round is a draw, then paper always wins
+        for strategy in [Strategy::CounterLast, Strategy::Frequency] {
+            let stats = simulate(&rules, &strategy, &rock, 100);
+            assert_eq!((99, 1, 0), (stats.wins, stats.draws, stats.losses));
+            assert_eq!(4 + 99 * 8, stats.score);
+        }
+
+        // Frequency sticks to the most played sign, counter-last does not
+        let mostly_paper = Strategy::Guide(vec![Sign::PAPER, Sign::PAPER, Sign::ROCK]);
+        let frequency = simulate(&rules, &Strategy::Frequency, &mostly_paper, 300);
+        let counter = simulate(&rules, &Strategy::CounterLast, &mostly_paper, 300);
+        assert!(frequency.score > counter.score);
+    }
+
+    #[test]
+    fn random() {
+        let rules = Rules::lizard_spock();
+        let first = simulate(&rules, &Strategy::Random(7), &Strategy::Random(8), 10_000);
+        assert_eq!(
+            first,
+            simulate(&rules, &Strategy::Random(7), &Strategy::Random(8), 10_000)
+        );
+        assert_eq!(10_000, first.wins + first.draws + first.losses);
+        // Random play wins and loses about as often, and draws one time in 5
+        assert!((first.win_rate() - first.loss_rate()).abs() < 0.05);
+        assert!((first.draw_rate() - 0.2).abs() < 0.02);
+    }
+
+    #[test]
+    fn report() {
+        let rules = Rules::classic();
+        let strategies = [
+            Strategy::Guide(vec![Sign::ROCK]),
+            Strategy::CounterLast,
+            Strategy::Random(1),
+        ];
+        let results = tournament(&rules, &strategies, 10);
+        assert_eq!(3, results.len());
+
+        let report = Report(results).to_string();
+        let lines = report.lines().collect::<Vec<_>>();
+        assert_eq!(
+            " Match                     |   Win |  Draw |  Loss | Expected score",
+            lines[0]
+        );
+        assert_eq!(
+            " guide vs counter-last     |  0.0% | 10.0% | 90.0% |           1.30",
+            lines[2]
+        );
+        assert!(lines[4].starts_with(" counter-last vs random(1) |"));
+    }
+}
@@ -0,0 +1,235 @@
+//! Ways of forming groups of elves, and the badge each group carries.
+
+use std::fmt;
+
+use aoc_common::{parser, Result, Span};
+
+use crate::{shared::Shared, Rucksack};
+
+/// How consecutive rucksacks are grouped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Grouping {
+    /// Consecutive groups of this size, refusing an incomplete last group
+    Chunks(usize),
+    /// Every run of this many consecutive rucksacks, overlapping each other
+    Windows(usize),
+    /// Groups separated by lines equal to this text, e.g. blank lines
+    Delimited(String),
+}
+
+/// Grouping described by `spec`: `chunks:N`, `windows:N`, or `delimited:TEXT`
+/// (`delimited:` for blank lines).
+pub fn grouping(spec: &str) -> std::result::Result<Grouping, String> {
+    let size = |value: &str| {
+        value
+            .parse::<usize>()
+            .ok()
+            .filter(|it| *it > 0)
+            .ok_or_else(|| format!("invalid group size `{}`", value))
+    };
+
+    match spec.split_once(':') {
+        Some(("chunks", value)) => Ok(Grouping::Chunks(size(value)?)),
+        Some(("windows", value)) => Ok(Grouping::Windows(size(value)?)),
+        Some(("delimited", text)) => Ok(Grouping::Delimited(text.into())),
+        _ => Err(format!(
+            "unknown grouping `{}`, expected `chunks:N`, `windows:N` or `delimited:TEXT`",
+            spec
+        )),
+    }
+}
+
+fn missing(count: usize, size: usize) -> String {
+    format!("{} more rucksack(s) to complete a group of {}", count, size)
+}
+
+fn rucksacks<'a>(lines: &[Span<'a>]) -> Result<Vec<Rucksack>> {
+    lines.iter().map(|it| Rucksack::try_from(*it)).collect()
+}
+
+/// Rucksacks of `input`, grouped.
+///
+/// A size of 0 is taken as 1.
+pub fn group(input: &str, grouping: &Grouping) -> Result<Vec<Vec<Rucksack>>> {
+    let lines = parser::lines(input).collect::<Vec<_>>();
+
+    match grouping {
+        // There is always a line, even an empty one
+        Grouping::Chunks(size) => {
+            chunks(&rucksacks(&lines)?, *size).map_err(|it| lines.last().unwrap().end_error(it))
+        }
+        Grouping::Windows(size) => {
+            let size = (*size).max(1);
+            let rucksacks = rucksacks(&lines)?;
+            if rucksacks.len() < size {
+                return Err(lines
+                    .last()
+                    .unwrap()
+                    .end_error(missing(size - rucksacks.len(), size)));
+            }
+            Ok(rucksacks.windows(size).map(<[_]>::to_vec).collect())
+        }
+        Grouping::Delimited(delimiter) => lines
+            .split(|it| it.text == delimiter.as_str())
+            .filter(|it| !it.is_empty())
+            .map(rucksacks)
+            .collect(),
+    }
+}
+
+/// Consecutive groups of `size` parsed rucksacks, refusing an incomplete
+/// last group.
+///
+/// A size of 0 is taken as 1.
+pub fn chunks(
+    rucksacks: &[Rucksack],
+    size: usize,
+) -> std::result::Result<Vec<Vec<Rucksack>>, String> {
+    let size = size.max(1);
+    match (size - rucksacks.len() % size) % size {
+        0 => {}
+        count => return Err(missing(count, size)),
+    }
+    Ok(rucksacks.chunks(size).map(<[_]>::to_vec).collect())
+}
+
+/// Badge of every group, printed as a table.
+pub struct Badges<'a>(pub &'a [Shared]);
+
+impl fmt::Display for Badges<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = self
+            .0
+            .iter()
+            .map(|shared| {
+                let lines = shared
+                    .lines
+                    .iter()
+                    .map(|it| it.to_string())
+                    .collect::<Vec<_>>();
+                // Groups without exactly one badge are anomalies, not badges
+                let (badge, priority) = match shared.items.len() {
+                    0 => ("no shared item".to_string(), "-".to_string()),
+                    1 => (
+                        shared.items.to_string(),
+                        shared.items.priority_sum().to_string(),
+                    ),
+                    count => (format!("{} shared items", count), "-".to_string()),
+                };
+                (lines.join(", "), badge, priority)
+            })
+            .collect::<Vec<_>>();
+        let width = |header: &str, cell: fn(&(String, String, String)) -> &String| {
+            rows.iter()
+                .map(|it| cell(it).len())
+                .fold(header.len(), usize::max)
+        };
+        let (lines, badge) = (width("Lines", |it| &it.0), width("Badge", |it| &it.1));
+
+        write!(f, " {:<lines$} | {:<badge$} | Priority", "Lines", "Badge")?;
+        write!(
+            f,
+            "\n-{}-+-{}-+---------",
+            "-".repeat(lines),
+            "-".repeat(badge)
+        )?;
+        for (members, items, priority) in rows.iter() {
+            write!(
+                f,
+                "\n {:<lines$} | {:<badge$} | {:>8}",
+                members, items, priority
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::ParseError;
+
+    use crate::{
+        groups::{chunks, group, grouping, Badges, Grouping},
+        shared::group_items,
+    };
+
+    fn lines(groups: &[Vec<crate::Rucksack>]) -> Vec<Vec<usize>> {
+        groups
+            .iter()
+            .map(|group| group.iter().map(|it| it.line).collect())
+            .collect()
+    }
+
+    #[test]
+    fn chunked() {
+        let groups = group("ab\ncd\nef\ngh", &Grouping::Chunks(2)).unwrap();
+        assert_eq!(vec![vec![1, 2], vec![3, 4]], lines(&groups));
+
+        assert_eq!(
+            Err(ParseError::new(
+                4,
+                3,
+                "2 more rucksack(s) to complete a group of 3"
+            )),
+            group("ab\ncd\nef\ngh", &Grouping::Chunks(3))
+        );
+
+        let rucksacks = groups.concat();
+        assert_eq!(Ok(groups), chunks(&rucksacks, 2));
+        assert_eq!(
+            Err("2 more rucksack(s) to complete a group of 3".into()),
+            chunks(&rucksacks, 3)
+        );
+        assert_eq!(Ok(vec![]), chunks(&[], 3));
+    }
+
+    #[test]
+    fn windows() {
+        let groups = group("ab\ncd\nef\ngh", &Grouping::Windows(3)).unwrap();
+        assert_eq!(vec![vec![1, 2, 3], vec![2, 3, 4]], lines(&groups));
+        assert!(group("ab", &Grouping::Windows(3)).is_err());
+    }
+
+    #[test]
+    fn delimited() {
+        let input = "ab\ncd\n\nef\n\n\ngh\nij\nkl";
+        let groups = group(input, &Grouping::Delimited("".into())).unwrap();
+        assert_eq!(vec![vec![1, 2], vec![4], vec![7, 8, 9]], lines(&groups));
+
+        let groups = group("ab\n--\ncd", &Grouping::Delimited("--".into())).unwrap();
+        assert_eq!(vec![vec![1], vec![3]], lines(&groups));
+        assert!(group("ab\n-\ncd", &Grouping::Delimited("--".into())).is_err());
+    }
+
+    #[test]
+    fn specs() {
+        assert_eq!(Ok(Grouping::Chunks(3)), grouping("chunks:3"));
+        assert_eq!(Ok(Grouping::Windows(2)), grouping("windows:2"));
+        assert_eq!(Ok(Grouping::Delimited("".into())), grouping("delimited:"));
+        assert_eq!(
+            Ok(Grouping::Delimited("--".into())),
+            grouping("delimited:--")
+        );
+        assert_eq!(Err("invalid group size `0`".into()), grouping("chunks:0"));
+        assert!(grouping("pairs").is_err());
+    }
+
+    #[test]
+    fn badges() {
+        let input = "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\n\nwMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\nttgJtRGJQctTZtZT\n\nab\ncd";
+        let groups = group(input, &Grouping::Delimited("".into())).unwrap();
+        let badges = groups.iter().map(|it| group_items(it)).collect::<Vec<_>>();
+
+        assert_eq!(
+            [
+                " Lines   | Badge          | Priority",
+                "---------+----------------+---------",
+                " 1, 2, 3 | r              |       18",
+                " 5, 6    | 4 shared items |        -",
+                " 8, 9    | no shared item |        -",
+            ]
+            .join("\n"),
+            Badges(&badges).to_string()
+        );
+    }
+}
@@ -0,0 +1,171 @@
+//! Sets of rucksack items, as bit masks indexed by priority.
+
+use std::{
+    fmt,
+    ops::{BitAnd, BitOr, Sub},
+};
+
+use aoc_common::{Result, Span};
+
+/// Items `a`-`z` (priorities 1 to 26) and `A`-`Z` (27 to 52), bit `n`
+/// standing for the item of priority `n`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+/// Priority of `item`, if it is an item at all.
+pub fn priority(item: char) -> Option<u32> {
+    match item {
+        'a'..='z' => Some(item as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(item as u32 - 'A' as u32 + 27),
+        _ => None,
+    }
+}
+
+/// Item of priority `priority`, the reverse of [`priority`].
+pub fn item(priority: u32) -> Option<char> {
+    match priority {
+        1..=26 => char::from_u32('a' as u32 + priority - 1),
+        27..=52 => char::from_u32('A' as u32 + priority - 27),
+        _ => None,
+    }
+}
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+
+    /// Items of `span`, failing on the first character that is not one.
+    pub fn parse(span: &Span) -> Result<Self> {
+        let mut res = Self::EMPTY;
+        for chr in span.chars() {
+            let item = chr.text.chars().next().unwrap_or_default();
+            if !res.insert(item) {
+                return Err(chr.error("an item (`a`-`z` or `A`-`Z`)"));
+            }
+        }
+        Ok(res)
+    }
+
+    /// Adds `item`, returning false when it is not an item.
+    pub fn insert(&mut self, item: char) -> bool {
+        match priority(item) {
+            Some(priority) => {
+                self.0 |= 1 << priority;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn contains(&self, item: char) -> bool {
+        priority(item).is_some_and(|it| self.0 & (1 << it) != 0)
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Priorities of the items, in increasing order.
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        (1..=52).filter(move |it| self.0 & (1 << it) != 0)
+    }
+
+    pub fn items(self) -> impl Iterator<Item = char> {
+        self.priorities().filter_map(item)
+    }
+
+    pub fn priority_sum(&self) -> u32 {
+        self.priorities().sum()
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        self.intersection(other)
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+impl Sub for ItemSet {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.difference(other)
+    }
+}
+
+impl fmt::Display for ItemSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.items().try_for_each(|it| write!(f, "{}", it))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{ParseError, Span};
+
+    use crate::items::{item, priority, ItemSet};
+
+    fn set(items: &str) -> ItemSet {
+        ItemSet::parse(&Span::new(1, 1, items)).unwrap()
+    }
+
+    #[test]
+    fn priorities() {
+        assert_eq!(Some(1), priority('a'));
+        assert_eq!(Some(26), priority('z'));
+        assert_eq!(Some(27), priority('A'));
+        assert_eq!(Some(52), priority('Z'));
+        assert_eq!(None, priority('@'));
+        assert_eq!(None, priority('é'));
+        for priority in 1..=52 {
+            assert_eq!(Some(priority), item(priority).and_then(super::priority));
+        }
+        assert_eq!(None, item(0));
+    }
+
+    #[test]
+    fn operations() {
+        let (a, b) = (set("vJrwpWtwJgWr"), set("hcsFMMfFFhFp"));
+        assert_eq!(set("p"), a & b);
+        assert_eq!(set("vJrwpWtgWhcsFMf"), a | b);
+        assert_eq!(set("vJrwWtg"), a - b);
+        assert_eq!(7, (a - b).len());
+        assert!(a.contains('W') && !a.contains('h') && !a.contains('1'));
+        assert_eq!(16, (a & b).priority_sum());
+        assert_eq!("gprtvwJW", a.to_string());
+        assert!(ItemSet::EMPTY.is_empty());
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Err(ParseError::new(3, 5, "an item (`a`-`z` or `A`-`Z`)").found("é")),
+            ItemSet::parse(&Span::new(3, 1, "abcdé"))
+        );
+    }
+}
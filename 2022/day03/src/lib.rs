@@ -0,0 +1,201 @@
+use aoc_common::{parser, Result, Solution, Span};
+
+mod groups;
+mod items;
+mod shared;
+
+pub use groups::{chunks, group, grouping, Badges, Grouping};
+pub use items::{item, priority, ItemSet};
+pub use shared::{group_items, priority_sum, shared_items, Anomalies, Shared, Validation};
+
+/// Rucksack of one line, split into its two compartments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rucksack {
+    pub line: usize,
+    pub left: ItemSet,
+    pub right: ItemSet,
+}
+
+impl Rucksack {
+    /// Every item of the rucksack.
+    pub fn items(&self) -> ItemSet {
+        self.left | self.right
+    }
+
+    /// Items found in both compartments.
+    pub fn shared(&self) -> ItemSet {
+        self.left & self.right
+    }
+}
+
+impl TryFrom<Span<'_>> for Rucksack {
+    type Error = aoc_common::ParseError;
+
+    fn try_from(line: Span) -> Result<Self> {
+        ItemSet::parse(&line)?;
+        let len = line.text.len();
+        if len % 2 == 1 {
+            return Err(line.error("an even number of items"));
+        }
+
+        Ok(Self {
+            line: line.line,
+            left: ItemSet::parse(&line.slice(0, len / 2))?,
+            right: ItemSet::parse(&line.slice(len / 2, len))?,
+        })
+    }
+}
+
+fn compute(rucksacks: &[Rucksack]) -> u32 {
+    // Parsing makes sure every rucksack shares exactly one item
+    shared_items(rucksacks)
+        .iter()
+        .map(|it| it.items.priority_sum())
+        .sum()
+}
+
+/// Badge of every group of three elves, failing on an incomplete last group
+/// or on a group not sharing exactly one item.
+pub fn badges(rucksacks: &[Rucksack]) -> std::result::Result<Vec<Shared>, String> {
+    let groups = chunks(rucksacks, 3)?
+        .iter()
+        .map(|it| group_items(it))
+        .collect::<Vec<_>>();
+    let anomalies = Anomalies::find(&groups);
+    if !anomalies.is_empty() {
+        return Err(anomalies.to_string());
+    }
+    Ok(groups)
+}
+
+fn compute_p2(rucksacks: &[Rucksack]) -> u32 {
+    badges(rucksacks)
+        .unwrap_or_else(|err| panic!("{}", err))
+        .iter()
+        .map(|it| it.items.priority_sum())
+        .sum()
+}
+
+/// Rucksacks of every line, whatever they share.
+pub fn parse_rucksacks(input: &str) -> Result<Vec<Rucksack>> {
+    // No rucksack at all, rather than an empty one
+    if input.trim().is_empty() {
+        return Ok(vec![]);
+    }
+    parser::lines(input).map(Rucksack::try_from).collect()
+}
+
+/// Rucksacks must share exactly one item between their compartments (see
+/// [`parse_rucksacks`] and [`Anomalies`] otherwise).
+///
+/// Part 2 panics when the rucksacks do not form whole groups of three sharing
+/// one badge each (see [`badges`]), which part 1 does not need.
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Rucksack>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let rucksacks = parse_rucksacks(input)?;
+        let lines = parser::lines(input);
+        for (rucksack, line) in rucksacks.iter().zip(lines) {
+            if rucksack.shared().len() != 1 {
+                return Err(line.error("compartments sharing exactly one item"));
+            }
+        }
+        Ok(rucksacks)
+    }
+
+    fn part1(rucksacks: &Self::Input) -> u32 {
+        compute(rucksacks)
+    }
+
+    fn part2(rucksacks: &Self::Input) -> u32 {
+        compute_p2(rucksacks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{badges, compute, compute_p2, group_items, parse_rucksacks, Day03};
+    use aoc_common::{input, ParseError, Solution};
+
+    #[test]
+    fn example() {
+        let rucksacks = Day03::parse(&input::raw("tests/example.txt")).unwrap();
+
+        let shared = rucksacks.iter().map(|it| it.shared().priority_sum());
+        assert_eq!(vec![16, 38, 42, 22, 20, 19], shared.collect::<Vec<_>>());
+        assert_eq!("p", rucksacks[0].shared().to_string());
+        assert_eq!("r", group_items(&rucksacks[..3]).items.to_string());
+        assert_eq!("Z", group_items(&rucksacks[3..]).items.to_string());
+
+        assert_eq!(157, compute(&rucksacks));
+        assert_eq!(70, compute_p2(&rucksacks));
+    }
+
+    #[test]
+    fn solution() {
+        let lines = Day03::parse(&input::raw("tests/example.txt")).unwrap();
+        assert_eq!(157, Day03::part1(&lines));
+        assert_eq!(70, Day03::part2(&lines));
+    }
+
+    #[test]
+    fn line_endings() {
+        let expected = Day03::parse(&input::raw("tests/example.txt")).unwrap();
+        for path in ["tests/example_crlf.txt", "tests/example_newline.txt"] {
+            let parsed = Day03::parse(&input::raw(path)).unwrap();
+            assert_eq!(Day03::part1(&expected), Day03::part1(&parsed));
+            assert_eq!(Day03::part2(&expected), Day03::part2(&parsed));
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Some(ParseError::new(2, 4, "an item (`a`-`z` or `A`-`Z`)").found("4")),
+            Day03::parse("abcA\nabc4\nab").err()
+        );
+        assert_eq!(
+            Some(ParseError::new(2, 1, "an even number of items").found("abc")),
+            Day03::parse("abcA\nabc\nab").err()
+        );
+    }
+
+    #[test]
+    fn anomalies() {
+        assert_eq!(
+            Some(ParseError::new(2, 1, "compartments sharing exactly one item").found("ab")),
+            Day03::parse("aa\nab\naa").err()
+        );
+        assert_eq!(
+            Some(ParseError::new(1, 1, "compartments sharing exactly one item").found("abab")),
+            Day03::parse("abab").err()
+        );
+        assert_eq!(3, parse_rucksacks("aa\nab\naa").unwrap().len());
+    }
+
+    #[test]
+    fn incomplete() {
+        // Part 1 does not need whole groups
+        let rucksacks = Day03::parse("aa\nbb").unwrap();
+        assert_eq!(3, Day03::part1(&rucksacks));
+        assert_eq!(
+            Err("1 more rucksack(s) to complete a group of 3".into()),
+            badges(&rucksacks)
+        );
+
+        let rucksacks = Day03::parse("").unwrap();
+        assert_eq!(0, Day03::part1(&rucksacks));
+        assert_eq!(0, Day03::part2(&rucksacks));
+
+        let rucksacks = Day03::parse("aa\nbb\ncc").unwrap();
+        assert_eq!(
+            Err("1 anomaly\n  lines 1-3: no shared item".into()),
+            badges(&rucksacks)
+        );
+    }
+}
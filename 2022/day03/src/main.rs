@@ -0,0 +1,51 @@
+use aoc2022_day03::{
+    badges, group, group_items, grouping, parse_rucksacks, shared_items, Anomalies, Badges, Day03,
+    Grouping,
+};
+use aoc_common::{solution, Solution};
+
+/// Prints `anomalies` of `name`, if any.
+fn report(name: &str, anomalies: Anomalies) {
+    if !anomalies.is_empty() {
+        println!("{}: {}", name, anomalies);
+    }
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let anomalies = solution::take_switch(&mut args, "-a", "--anomalies");
+    let grouping = solution::take_options(&mut args, "-g", "--grouping")
+        .and_then(|it| it.last().map(|it| grouping(it)).transpose())
+        .unwrap_or_else(|err| solution::fail(&err))
+        .unwrap_or(Grouping::Chunks(3));
+    // The input is picked from the remaining arguments
+    let (source, raw) = solution::read_from(
+        args.into_iter(),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/myinput.txt"),
+    );
+    // Incomplete groups are reported along with their line
+    let grouped = solution::or_exit(group(&raw, &grouping), &source, &raw);
+    let groups = grouped.iter().map(|it| group_items(it)).collect::<Vec<_>>();
+    // Delimiter lines are not rucksacks, and do not fit the puzzle answers
+    let delimited = matches!(grouping, Grouping::Delimited(_));
+
+    if anomalies {
+        // Lists the rucksacks and groups not sharing exactly one item, which
+        // the answers refuse
+        let rucksacks = match delimited {
+            true => grouped.concat(),
+            false => solution::or_exit(parse_rucksacks(&raw), &source, &raw),
+        };
+        report("Rucksacks", Anomalies::find(&shared_items(&rucksacks)));
+        return report("Groups", Anomalies::find(&groups));
+    }
+
+    if !delimited {
+        let parsed = solution::or_exit(Day03::parse(&raw), &source, &raw);
+        println!("Result: {}", Day03::part1(&parsed));
+        // Part 2 always needs groups of three
+        badges(&parsed).unwrap_or_else(|err| solution::fail(&err));
+        println!("Result (part2): {}", Day03::part2(&parsed));
+    }
+    println!("{}", Badges(&groups));
+}
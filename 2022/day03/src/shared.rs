@@ -0,0 +1,174 @@
+//! Items shared inside rucksacks or groups, and the ones that do not share
+//! exactly one.
+
+use std::fmt;
+
+use crate::{items::ItemSet, Rucksack};
+
+/// Items shared by the compartments of a rucksack, or by a group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shared {
+    /// Lines of the rucksacks involved, from 1
+    pub lines: Vec<usize>,
+    pub items: ItemSet,
+}
+
+impl Shared {
+    /// Shared items along with their priorities, in increasing priority.
+    pub fn priorities(&self) -> Vec<(char, u32)> {
+        self.items.items().zip(self.items.priorities()).collect()
+    }
+
+    fn location(&self) -> String {
+        match self.lines.as_slice() {
+            [line] => format!("line {}", line),
+            [first, .., last] if last - first + 1 == self.lines.len() => {
+                format!("lines {}-{}", first, last)
+            }
+            lines => {
+                let lines = lines.iter().map(|it| it.to_string()).collect::<Vec<_>>();
+                format!("lines {}", lines.join(", "))
+            }
+        }
+    }
+}
+
+/// Items found in both compartments, rucksack by rucksack.
+pub fn shared_items(rucksacks: &[Rucksack]) -> Vec<Shared> {
+    rucksacks
+        .iter()
+        .map(|it| Shared {
+            lines: vec![it.line],
+            items: it.shared(),
+        })
+        .collect()
+}
+
+/// Items carried by every rucksack of `group`.
+pub fn group_items(group: &[Rucksack]) -> Shared {
+    Shared {
+        lines: group.iter().map(|it| it.line).collect(),
+        items: group
+            .iter()
+            .map(Rucksack::items)
+            .reduce(ItemSet::intersection)
+            .unwrap_or_default(),
+    }
+}
+
+/// How rucksacks or groups not sharing exactly one item are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Validation {
+    /// Sums the priorities of every shared item
+    Lenient,
+    /// Refuses to sum anything while there are anomalies
+    Strict,
+}
+
+/// Rucksacks or groups not sharing exactly one item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Anomalies(pub Vec<Shared>);
+
+impl Anomalies {
+    pub fn find(shared: &[Shared]) -> Self {
+        Self(
+            shared
+                .iter()
+                .filter(|it| it.items.len() != 1)
+                .cloned()
+                .collect(),
+        )
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Display for Anomalies {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.len() {
+            1 => write!(f, "1 anomaly")?,
+            count => write!(f, "{} anomalies", count)?,
+        }
+        for shared in self.0.iter() {
+            write!(f, "\n  {}: ", shared.location())?;
+            if shared.items.is_empty() {
+                write!(f, "no shared item")?;
+            } else {
+                let items = shared
+                    .priorities()
+                    .iter()
+                    .map(|(item, priority)| format!("{} ({})", item, priority))
+                    .collect::<Vec<_>>();
+                write!(
+                    f,
+                    "{} shared items: {}",
+                    shared.items.len(),
+                    items.join(", ")
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Sum of the priorities of every shared item, failing in strict mode when
+/// something does not share exactly one item.
+pub fn priority_sum(shared: &[Shared], validation: Validation) -> Result<u32, Anomalies> {
+    if validation == Validation::Strict {
+        let anomalies = Anomalies::find(shared);
+        if !anomalies.is_empty() {
+            return Err(anomalies);
+        }
+    }
+    Ok(shared.iter().map(|it| it.items.priority_sum()).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parse_rucksacks,
+        shared::{group_items, priority_sum, shared_items, Anomalies, Validation},
+    };
+
+    #[test]
+    fn shared() {
+        let rucksacks = parse_rucksacks("abca\nabcd\nAbAd").unwrap();
+        let shared = shared_items(&rucksacks);
+        assert_eq!(vec![('a', 1)], shared[0].priorities());
+        assert!(shared[1].items.is_empty());
+        assert_eq!(vec![3], shared[2].lines);
+
+        let group = group_items(&rucksacks);
+        assert_eq!(vec![1, 2, 3], group.lines);
+        assert_eq!(vec![('b', 2)], group.priorities());
+    }
+
+    #[test]
+    fn anomalies() {
+        let rucksacks = parse_rucksacks("abca\nabcd\naBaB\nabcd\nabcd\nabcd").unwrap();
+        let shared = shared_items(&rucksacks);
+        assert_eq!(Ok(1 + 1 + 28), priority_sum(&shared, Validation::Lenient));
+
+        let anomalies = priority_sum(&shared, Validation::Strict).unwrap_err();
+        assert_eq!(
+            [
+                "5 anomalies",
+                "  line 2: no shared item",
+                "  line 3: 2 shared items: a (1), B (28)",
+                "  line 4: no shared item",
+                "  line 5: no shared item",
+                "  line 6: no shared item",
+            ]
+            .join("\n"),
+            anomalies.to_string()
+        );
+
+        let groups = rucksacks.chunks(3).map(group_items).collect::<Vec<_>>();
+        assert_eq!(
+            "1 anomaly\n  lines 4-6: 4 shared items: a (1), b (2), c (3), d (4)",
+            Anomalies::find(&groups).to_string()
+        );
+    }
+}
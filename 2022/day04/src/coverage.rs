@@ -0,0 +1,129 @@
+//! Sections covered by the assignments of every elf at once.
+
+use crate::{interval::Interval, set::IntervalSet, team::Team};
+
+/// Assignments of every elf of every team.
+pub fn assignments(teams: &[Team]) -> Vec<Interval> {
+    teams
+        .iter()
+        .flat_map(|it| it.elves.iter().copied())
+        .collect()
+}
+
+/// Number of elves assigned to each covered section, as runs of sections
+/// sharing the same count.
+pub fn depths(assignments: &[Interval]) -> Vec<(Interval, usize)> {
+    // Changes of the count, an assignment ending on the last section never
+    // being removed
+    let mut events = vec![];
+    for interval in assignments {
+        events.push((interval.start(), 1));
+        if let Some(after) = interval.end().checked_add(1) {
+            events.push((after, -1));
+        }
+    }
+    events.sort();
+
+    let mut res = vec![];
+    let mut depth = 0_i64;
+    for (idx, (section, change)) in events.iter().enumerate() {
+        depth += change;
+        let next = match events.get(idx + 1) {
+            Some((next, _)) if next == section => continue,
+            Some((next, _)) => next - 1,
+            None => u64::MAX,
+        };
+        if depth > 0 {
+            res.push((Interval::new(*section, next).unwrap(), depth as usize));
+        }
+    }
+    res
+}
+
+/// Sections assigned to at least one elf.
+pub fn covered(assignments: &[Interval]) -> IntervalSet {
+    assignments.iter().copied().collect()
+}
+
+/// Sections assigned to more than `count` elves.
+pub fn covered_by_more_than(assignments: &[Interval], count: usize) -> IntervalSet {
+    depths(assignments)
+        .into_iter()
+        .filter(|(_, depth)| *depth > count)
+        .map(|(interval, _)| interval)
+        .collect()
+}
+
+/// Sections of `bounds` assigned to nobody.
+pub fn gaps(assignments: &[Interval], bounds: Interval) -> IntervalSet {
+    IntervalSet::from(bounds).difference(&covered(assignments))
+}
+
+/// Smallest interval holding every assignment.
+pub fn bounds(assignments: &[Interval]) -> Option<Interval> {
+    let start = assignments.iter().map(Interval::start).min()?;
+    let end = assignments.iter().map(Interval::end).max()?;
+    Interval::new(start, end)
+}
+
+/// Most elves assigned to a same section.
+pub fn max_depth(assignments: &[Interval]) -> usize {
+    depths(assignments)
+        .into_iter()
+        .map(|(_, depth)| depth)
+        .max()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input, Solution};
+
+    use crate::{
+        coverage::{assignments, bounds, covered, covered_by_more_than, depths, gaps, max_depth},
+        interval::Interval,
+        Day04,
+    };
+
+    fn range(start: u64, end: u64) -> Interval {
+        Interval::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn depth() {
+        let assignments = [range(1, 4), range(3, 6), range(4, 4), range(9, 9)];
+        assert_eq!(
+            vec![
+                (range(1, 2), 1),
+                (range(3, 3), 2),
+                (range(4, 4), 3),
+                (range(5, 6), 1),
+                (range(9, 9), 1)
+            ],
+            depths(&assignments)
+        );
+        assert_eq!(3, max_depth(&assignments));
+        assert_eq!(0, max_depth(&[]));
+        assert_eq!(
+            vec![(range(5, u64::MAX), 2)],
+            depths(&[range(5, u64::MAX), range(5, u64::MAX)])
+        );
+    }
+
+    #[test]
+    fn example() {
+        let teams = Day04::parse(&input::raw("tests/example.txt")).unwrap();
+        let assignments = assignments(&teams);
+
+        assert_eq!("2-9", covered(&assignments).to_string());
+        assert_eq!(8, covered(&assignments).sections());
+        assert_eq!("2-8", covered_by_more_than(&assignments, 2).to_string());
+        assert_eq!("3-7", covered_by_more_than(&assignments, 4).to_string());
+        assert_eq!("4-6", covered_by_more_than(&assignments, 6).to_string());
+        assert!(covered_by_more_than(&assignments, 8).is_empty());
+        assert_eq!(8, max_depth(&assignments));
+        assert_eq!(Some(range(2, 9)), bounds(&assignments));
+        assert_eq!("1-1, 10-12", gaps(&assignments, range(1, 12)).to_string());
+        assert!(gaps(&assignments, range(2, 9)).is_empty());
+    }
+}
@@ -0,0 +1,134 @@
+//! Inclusive ranges of sections, kept as their two bounds.
+
+use std::fmt;
+
+use aoc_common::{Result, Span};
+
+/// Sections `start` to `end`, both included, `start` never after `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    start: u64,
+    end: u64,
+}
+
+impl Interval {
+    /// Sections `start` to `end`, unless `start` comes after `end`.
+    pub fn new(start: u64, end: u64) -> Option<Self> {
+        (start <= end).then_some(Self { start, end })
+    }
+
+    /// Range like `2-4`.
+    pub fn parse(range: &Span) -> Result<Self> {
+        let bounds = range.split("-");
+        if bounds.len() != 2 {
+            return Err(range.error("a range like `2-4`"));
+        }
+        let start = bounds[0].parse::<u64>("a section number")?;
+        let end = bounds[1].parse::<u64>("a section number")?;
+        Self::new(start, end).ok_or_else(|| range.error("a range not ending before its start"))
+    }
+
+    pub fn start(&self) -> u64 {
+        self.start
+    }
+
+    pub fn end(&self) -> u64 {
+        self.end
+    }
+
+    /// Number of sections, saturating for the interval of every section.
+    pub fn sections(&self) -> u64 {
+        (self.end - self.start).saturating_add(1)
+    }
+
+    pub fn contains(&self, section: u64) -> bool {
+        self.start <= section && section <= self.end
+    }
+
+    /// Whether every section of `other` is also in this interval.
+    pub fn encloses(&self, other: &Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Whether both intervals have at least one section in common.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Sections in both intervals.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Sections in either interval, as long as they leave no gap between
+    /// them.
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        let touching =
+            self.start <= other.end.saturating_add(1) && other.start <= self.end.saturating_add(1);
+        touching.then(|| Self {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        })
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{ParseError, Span};
+
+    use crate::interval::Interval;
+
+    fn interval(start: u64, end: u64) -> Interval {
+        Interval::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Ok(interval(2, 4)), Interval::parse(&Span::new(1, 1, "2-4")));
+        assert_eq!(
+            Ok(interval(1, 1_000_000_000)),
+            Interval::parse(&Span::new(1, 1, "1-1000000000"))
+        );
+        assert_eq!(
+            Err(ParseError::new(1, 5, "a range not ending before its start").found("4-2")),
+            Interval::parse(&Span::new(1, 5, "4-2"))
+        );
+        assert_eq!(
+            Err(ParseError::new(1, 3, "a section number").found("")),
+            Interval::parse(&Span::new(1, 1, "2-"))
+        );
+        assert_eq!(
+            Err(ParseError::new(1, 1, "a range like `2-4`").found("2-4-6")),
+            Interval::parse(&Span::new(1, 1, "2-4-6"))
+        );
+        assert_eq!(None, Interval::new(3, 2));
+    }
+
+    #[test]
+    fn operations() {
+        let (a, b) = (interval(2, 8), interval(3, 7));
+        assert!(a.encloses(&b) && !b.encloses(&a) && a.encloses(&a));
+        assert!(a.overlaps(&b) && !interval(2, 4).overlaps(&interval(6, 8)));
+        assert!(interval(5, 7).overlaps(&interval(7, 9)));
+        assert!(a.contains(2) && a.contains(8) && !a.contains(9));
+        assert_eq!((7, "2-8".to_string()), (a.sections(), a.to_string()));
+
+        assert_eq!(
+            Some(interval(7, 7)),
+            interval(5, 7).intersection(&interval(7, 9))
+        );
+        assert_eq!(None, interval(2, 4).intersection(&interval(6, 8)));
+        assert_eq!(Some(interval(2, 8)), interval(2, 4).union(&interval(5, 8)));
+        assert_eq!(None, interval(2, 4).union(&interval(6, 8)));
+        assert_eq!(
+            Some(interval(0, u64::MAX)),
+            interval(0, u64::MAX).union(&interval(u64::MAX, u64::MAX))
+        );
+    }
+}
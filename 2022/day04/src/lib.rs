@@ -0,0 +1,115 @@
+use aoc_common::{parser, Result, Solution};
+
+mod coverage;
+mod interval;
+mod set;
+mod team;
+
+pub use coverage::{assignments, bounds, covered, covered_by_more_than, depths, gaps, max_depth};
+pub use interval::Interval;
+pub use set::IntervalSet;
+pub use team::{Matrix, Redundancy, Relation, Team};
+
+fn calculate_score(teams: &[Team]) -> i32 {
+    teams.iter().filter(|it| it.any_enclosed()).count() as i32
+}
+
+fn calculate_score_p2(teams: &[Team]) -> i32 {
+    teams.iter().filter(|it| it.any_overlap()).count() as i32
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Team>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parser::lines(input).map(|it| Team::parse(&it)).collect()
+    }
+
+    fn part1(teams: &Self::Input) -> i32 {
+        calculate_score(teams)
+    }
+
+    fn part2(teams: &Self::Input) -> i32 {
+        calculate_score_p2(teams)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{calculate_score, calculate_score_p2, Day04, Interval};
+    use aoc_common::{input, ParseError, Solution};
+
+    #[test]
+    fn example() {
+        let teams = Day04::parse(&input::raw("tests/example.txt")).unwrap();
+
+        let range = |start, end| Interval::new(start, end).unwrap();
+        assert_eq!(vec![range(2, 4), range(6, 8)], teams[0].elves);
+        assert_eq!(vec![range(6, 6), range(4, 6)], teams[4].elves);
+        assert_eq!(5, teams[4].line);
+
+        let enclosed = teams.iter().map(|it| it.any_enclosed()).collect::<Vec<_>>();
+        assert_eq!(vec![false, false, false, true, true, false], enclosed);
+        let overlapping = teams.iter().map(|it| it.any_overlap()).collect::<Vec<_>>();
+        assert_eq!(vec![false, false, true, true, true, true], overlapping);
+
+        assert_eq!(2, calculate_score(&teams));
+        assert_eq!(4, calculate_score_p2(&teams));
+    }
+
+    #[test]
+    fn teams() {
+        let teams = Day04::parse("1-2,3-4,5-6\n1-2,2-3\n1-9,2-3,8-9").unwrap();
+        assert_eq!(1, Day04::part1(&teams));
+        assert_eq!(2, Day04::part2(&teams));
+        assert_eq!(2, teams[2].redundancies().len());
+    }
+
+    #[test]
+    fn large() {
+        let pairs = Day04::parse("1-1000000000,2-999999999\n1-5,1000000000-2000000000").unwrap();
+        assert_eq!(1, Day04::part1(&pairs));
+        assert_eq!(1, Day04::part2(&pairs));
+    }
+
+    #[test]
+    fn solution() {
+        let pairs = Day04::parse(&input::raw("tests/example.txt")).unwrap();
+        assert_eq!(2, Day04::part1(&pairs));
+        assert_eq!(4, Day04::part2(&pairs));
+    }
+
+    #[test]
+    fn line_endings() {
+        let expected = Day04::parse(&input::raw("tests/example.txt")).unwrap();
+        for path in ["tests/example_crlf.txt", "tests/example_newline.txt"] {
+            let parsed = Day04::parse(&input::raw(path)).unwrap();
+            assert_eq!(Day04::part1(&expected), Day04::part1(&parsed));
+            assert_eq!(Day04::part2(&expected), Day04::part2(&parsed));
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Some(ParseError::new(2, 5, "a section number").found("x")),
+            Day04::parse("2-4,6-8\n2-3,x-5").err()
+        );
+        assert_eq!(
+            Some(ParseError::new(1, 1, "a range like `2-4`").found("2")),
+            Day04::parse("2,6-8").err()
+        );
+        assert_eq!(
+            Some(ParseError::new(1, 1, "at least two ranges separated by `,`").found("2-4")),
+            Day04::parse("2-4").err()
+        );
+        assert_eq!(
+            Some(ParseError::new(1, 5, "a range not ending before its start").found("8-6")),
+            Day04::parse("2-4,8-6").err()
+        );
+    }
+}
@@ -0,0 +1,26 @@
+use aoc2022_day04::{assignments, bounds, covered, covered_by_more_than, gaps, max_depth, Day04};
+use aoc_common::{solution, Solution};
+
+fn main() {
+    let parsed = solution::load::<Day04>(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/myinput.txt"));
+    println!("Result: {}", Day04::part1(&parsed));
+    println!("Result part2: {}", Day04::part2(&parsed));
+
+    let redundant = parsed.iter().map(|it| it.redundant_elves().len());
+    println!("Redundant elves: {}", redundant.sum::<usize>());
+
+    let assignments = assignments(&parsed);
+    println!("Sections covered: {}", covered(&assignments).sections());
+    println!(
+        "Sections covered by more than one elf: {}",
+        covered_by_more_than(&assignments, 1).sections()
+    );
+    if let Some(bounds) = bounds(&assignments) {
+        let gaps = gaps(&assignments, bounds);
+        match gaps.is_empty() {
+            true => println!("Gaps in {}: none", bounds),
+            false => println!("Gaps in {}: {}", bounds, gaps),
+        }
+    }
+    println!("Maximum overlap: {} elves", max_depth(&assignments));
+}
@@ -0,0 +1,154 @@
+//! Sets of sections, kept as disjoint intervals.
+
+use std::fmt;
+
+use crate::interval::Interval;
+
+/// Sections of any number of intervals, stored in order with overlapping or
+/// adjacent intervals merged.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet(Vec<Interval>);
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Disjoint intervals of the set, in increasing order.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Number of sections, saturating like [`Interval::sections`].
+    pub fn sections(&self) -> u64 {
+        self.0
+            .iter()
+            .fold(0, |acc, it| acc.saturating_add(it.sections()))
+    }
+
+    pub fn contains(&self, section: u64) -> bool {
+        let idx = self.0.partition_point(|it| it.end() < section);
+        self.0.get(idx).is_some_and(|it| it.contains(section))
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        // Intervals before and after the ones merging with the new one
+        let first = self
+            .0
+            .partition_point(|it| it.end().saturating_add(1) < interval.start());
+        let last = self
+            .0
+            .partition_point(|it| it.start() <= interval.end().saturating_add(1));
+        let merged = self.0[first..last]
+            .iter()
+            .fold(interval, |acc, it| acc.union(it).unwrap());
+        self.0.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut res = self.clone();
+        other.0.iter().for_each(|it| res.insert(*it));
+        res
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut res = vec![];
+        let (mut left, mut right) = (self.0.iter().peekable(), other.0.iter().peekable());
+        while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+            res.extend(a.intersection(b));
+            // The interval ending first cannot meet anything further
+            if a.end() < b.end() {
+                left.next();
+            } else {
+                right.next();
+            }
+        }
+        Self(res)
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut res = vec![];
+        for interval in self.0.iter() {
+            let mut rest = Some(*interval);
+            for cut in other.0.iter().filter(|it| it.overlaps(interval)) {
+                let Some(current) = rest else { break };
+                if cut.start() > current.start() {
+                    res.extend(Interval::new(current.start(), cut.start() - 1));
+                }
+                rest = cut
+                    .end()
+                    .checked_add(1)
+                    .and_then(|start| Interval::new(start, current.end()));
+            }
+            res.extend(rest);
+        }
+        Self(res)
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<T: IntoIterator<Item = Interval>>(iter: T) -> Self {
+        let mut res = Self::new();
+        iter.into_iter().for_each(|it| res.insert(it));
+        res
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        Self(vec![interval])
+    }
+}
+
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let intervals = self.0.iter().map(|it| it.to_string()).collect::<Vec<_>>();
+        write!(f, "{}", intervals.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{interval::Interval, set::IntervalSet};
+
+    fn set(intervals: &[(u64, u64)]) -> IntervalSet {
+        intervals
+            .iter()
+            .map(|(start, end)| Interval::new(*start, *end).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn merge() {
+        assert_eq!("2-9", set(&[(2, 4), (6, 8), (5, 5), (7, 9)]).to_string());
+        assert_eq!("1-3, 5-9", set(&[(5, 6), (1, 3), (6, 9)]).to_string());
+        assert_eq!("1-10", set(&[(3, 4), (6, 7), (1, 10)]).to_string());
+
+        let sections = set(&[(2, 4), (10, 12), (6, 6)]);
+        assert_eq!(3, sections.intervals().len());
+        assert_eq!(7, sections.sections());
+        assert!(sections.contains(11) && sections.contains(6) && !sections.contains(5));
+        assert!(IntervalSet::new().is_empty() && !IntervalSet::new().contains(0));
+    }
+
+    #[test]
+    fn algebra() {
+        let (a, b) = (set(&[(1, 5), (10, 20)]), set(&[(4, 12), (18, 30)]));
+        assert_eq!("1-30", a.union(&b).to_string());
+        assert_eq!("4-5, 10-12, 18-20", a.intersection(&b).to_string());
+        assert_eq!("1-3, 13-17", a.difference(&b).to_string());
+        assert_eq!("6-9, 21-30", b.difference(&a).to_string());
+        assert!(a.difference(&a).is_empty());
+
+        let all = set(&[(0, u64::MAX)]);
+        assert_eq!(
+            "0-9, 21-18446744073709551615",
+            all.difference(&set(&[(10, 20)])).to_string()
+        );
+        assert!(a.difference(&all).is_empty());
+    }
+}
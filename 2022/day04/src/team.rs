@@ -0,0 +1,240 @@
+//! Lines assigning sections to any number of elves, and how the assignments
+//! of a line relate to each other.
+
+use std::fmt;
+
+use aoc_common::{Result, Span};
+
+use crate::interval::Interval;
+
+/// Assignments of one line, an elf per comma-separated range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Team {
+    pub line: usize,
+    pub elves: Vec<Interval>,
+}
+
+impl Team {
+    pub fn parse(line: &Span) -> Result<Self> {
+        let ranges = line.split(",");
+        if ranges.len() < 2 {
+            return Err(line.error("at least two ranges separated by `,`"));
+        }
+        Ok(Self {
+            line: line.line,
+            elves: ranges.iter().map(Interval::parse).collect::<Result<_>>()?,
+        })
+    }
+
+    /// Every two distinct elves, in order.
+    pub fn pairs(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let count = self.elves.len();
+        (0..count).flat_map(move |first| (first + 1..count).map(move |second| (first, second)))
+    }
+
+    pub fn relation(&self, first: usize, second: usize) -> Relation {
+        Relation::of(&self.elves[first], &self.elves[second])
+    }
+
+    /// Relation of every elf (rows) to every elf (columns).
+    pub fn matrix(&self) -> Matrix {
+        Matrix(
+            self.elves
+                .iter()
+                .map(|row| self.elves.iter().map(|it| Relation::of(row, it)).collect())
+                .collect(),
+        )
+    }
+
+    /// Whether an elf has its whole assignment done by another one.
+    pub fn any_enclosed(&self) -> bool {
+        self.pairs()
+            .any(|(first, second)| self.relation(first, second).encloses())
+    }
+
+    /// Whether two elves share at least one section.
+    pub fn any_overlap(&self) -> bool {
+        self.pairs()
+            .any(|(first, second)| self.relation(first, second) != Relation::Disjoint)
+    }
+
+    /// Elves whose whole assignment is done by another elf of the team, an
+    /// elf with the same assignment as an earlier one being the redundant one.
+    pub fn redundancies(&self) -> Vec<Redundancy> {
+        let mut res = vec![];
+        for (first, second) in self.pairs() {
+            let (redundant, by) = match self.relation(first, second) {
+                Relation::Equal | Relation::Contains => (second, first),
+                Relation::ContainedBy => (first, second),
+                Relation::Disjoint | Relation::Overlaps => continue,
+            };
+            res.push(Redundancy {
+                line: self.line,
+                elf: redundant,
+                range: self.elves[redundant],
+                by,
+                by_range: self.elves[by],
+            });
+        }
+        res
+    }
+
+    /// Elves, from 0, covered by at least one other elf, each counted once
+    /// however many elves cover it.
+    pub fn redundant_elves(&self) -> Vec<usize> {
+        let mut elves = self
+            .redundancies()
+            .iter()
+            .map(|it| it.elf)
+            .collect::<Vec<_>>();
+        elves.sort_unstable();
+        elves.dedup();
+        elves
+    }
+}
+
+/// How the assignment of an elf relates to the one of another elf.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    Disjoint,
+    /// Sharing sections, each also having sections of its own
+    Overlaps,
+    /// Holding every section of the other one, and more
+    Contains,
+    /// Having every section in the other one, which has more
+    ContainedBy,
+    Equal,
+}
+
+impl Relation {
+    pub fn of(first: &Interval, second: &Interval) -> Self {
+        match (first.encloses(second), second.encloses(first)) {
+            (true, true) => Self::Equal,
+            (true, false) => Self::Contains,
+            (false, true) => Self::ContainedBy,
+            _ if first.overlaps(second) => Self::Overlaps,
+            _ => Self::Disjoint,
+        }
+    }
+
+    /// Whether one of the assignments holds the other one.
+    pub fn encloses(&self) -> bool {
+        matches!(self, Self::Contains | Self::ContainedBy | Self::Equal)
+    }
+
+    fn symbol(&self) -> char {
+        match self {
+            Self::Disjoint => '.',
+            Self::Overlaps => 'x',
+            Self::Contains => '>',
+            Self::ContainedBy => '<',
+            Self::Equal => '=',
+        }
+    }
+}
+
+/// Relations between the elves of a team, printed as a grid of `.`
+/// (disjoint), `x` (overlapping), `>` (containing), `<` (contained) and `=`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix(pub Vec<Vec<Relation>>);
+
+impl fmt::Display for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = self
+            .0
+            .iter()
+            .map(|row| row.iter().map(Relation::symbol).collect::<String>())
+            .collect::<Vec<_>>();
+        write!(f, "{}", rows.join("\n"))
+    }
+}
+
+/// Elf whose whole assignment is done by another elf of its line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Redundancy {
+    pub line: usize,
+    /// Position of the redundant elf on the line, from 0
+    pub elf: usize,
+    pub range: Interval,
+    /// Position of the elf covering it
+    pub by: usize,
+    pub by_range: Interval,
+}
+
+impl fmt::Display for Redundancy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: elf {} ({}) is covered by elf {} ({})",
+            self.line,
+            self.elf + 1,
+            self.range,
+            self.by + 1,
+            self.by_range
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{ParseError, Span};
+
+    use crate::team::{Relation, Team};
+
+    fn team(line: &str) -> Team {
+        Team::parse(&Span::new(1, 1, line)).unwrap()
+    }
+
+    #[test]
+    fn relations() {
+        let team = team("2-8,3-7,6-9,2-8,10-12");
+        assert_eq!(5, team.elves.len());
+        assert_eq!(10, team.pairs().count());
+        assert_eq!(Relation::Contains, team.relation(0, 1));
+        assert_eq!(Relation::ContainedBy, team.relation(1, 0));
+        assert_eq!(Relation::Overlaps, team.relation(1, 2));
+        assert_eq!(Relation::Equal, team.relation(0, 3));
+        assert_eq!(Relation::Disjoint, team.relation(2, 4));
+        assert_eq!(
+            ["=>x=.", "<=x<.", "xx=x.", "=>x=.", "....="].join("\n"),
+            team.matrix().to_string()
+        );
+    }
+
+    #[test]
+    fn redundancies() {
+        let redundancies = team("2-8,3-7,6-9,2-8")
+            .redundancies()
+            .iter()
+            .map(|it| it.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "line 1: elf 2 (3-7) is covered by elf 1 (2-8)",
+                "line 1: elf 4 (2-8) is covered by elf 1 (2-8)",
+                "line 1: elf 2 (3-7) is covered by elf 4 (2-8)",
+            ],
+            redundancies
+        );
+        // Elf 2 is covered twice
+        assert_eq!(vec![1, 3], team("2-8,3-7,6-9,2-8").redundant_elves());
+
+        let apart = team("1-2,3-4,5-6");
+        assert!(apart.redundancies().is_empty());
+        assert!(!apart.any_enclosed() && !apart.any_overlap());
+        let chained = team("1-2,2-3,5-6");
+        assert!(!chained.any_enclosed() && chained.any_overlap());
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Err(ParseError::new(1, 1, "at least two ranges separated by `,`").found("2-4")),
+            Team::parse(&Span::new(1, 1, "2-4"))
+        );
+        assert_eq!(
+            Err(ParseError::new(1, 9, "a section number").found("x")),
+            Team::parse(&Span::new(1, 1, "1-2,3-4,x-6"))
+        );
+    }
+}
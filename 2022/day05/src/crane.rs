@@ -0,0 +1,191 @@
+//! Crane models, all moving crates through the same lifting core.
+
+use std::fmt;
+
+/// Moves the `count` top crates of `from` on top of `to`, keeping their
+/// order, as much as there are.
+pub(crate) fn lift(stacks: &mut [Vec<String>], from: usize, to: usize, count: usize) {
+    let source = &mut stacks[from];
+    let moved = source.split_off(source.len().saturating_sub(count));
+    stacks[to].extend(moved);
+}
+
+/// How a crane splits a move into lifts.
+pub trait Crane: fmt::Display {
+    /// Sizes of the successive lifts moving `qty` crates for the instruction
+    /// at `step`, from 0, each lift keeping the order of its crates.
+    fn lifts(&self, step: usize, qty: usize) -> Vec<usize>;
+}
+
+/// Moves crates one at a time (part 1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn lifts(&self, _: usize, qty: usize) -> Vec<usize> {
+        vec![1; qty]
+    }
+}
+
+impl fmt::Display for CrateMover9000 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CrateMover 9000")
+    }
+}
+
+/// Moves every crate of an instruction at once (part 2).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn lifts(&self, _: usize, qty: usize) -> Vec<usize> {
+        vec![qty]
+    }
+}
+
+impl fmt::Display for CrateMover9001 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CrateMover 9001")
+    }
+}
+
+/// Lifts at most this many crates at once, taking them from the top of the
+/// stack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capacity(pub usize);
+
+impl Crane for Capacity {
+    fn lifts(&self, _: usize, qty: usize) -> Vec<usize> {
+        let max = self.0.max(1);
+        let mut res = vec![max; qty / max];
+        if !qty.is_multiple_of(max) {
+            res.push(qty % max);
+        }
+        res
+    }
+}
+
+impl fmt::Display for Capacity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "capacity {}", self.0)
+    }
+}
+
+/// Moves crates by batches of this size, the last one holding the rest, as
+/// a crane of this capacity does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Batches(pub usize);
+
+impl Crane for Batches {
+    fn lifts(&self, step: usize, qty: usize) -> Vec<usize> {
+        Capacity(self.0).lifts(step, qty)
+    }
+}
+
+impl fmt::Display for Batches {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "batches of {}", self.0)
+    }
+}
+
+/// Hands every instruction to the next crane, starting over after the last.
+pub struct Rotating(pub Vec<Box<dyn Crane>>);
+
+impl Crane for Rotating {
+    fn lifts(&self, step: usize, qty: usize) -> Vec<usize> {
+        match self.0.len() {
+            0 => CrateMover9000.lifts(step, qty),
+            count => self.0[step % count].lifts(step / count, qty),
+        }
+    }
+}
+
+impl fmt::Display for Rotating {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cranes = self.0.iter().map(|it| it.to_string()).collect::<Vec<_>>();
+        write!(f, "rotating {}", cranes.join(", "))
+    }
+}
+
+/// Crane described by `spec`: `9000`, `9001`, `capacity:N`, `batches:N`, or
+/// cranes taking turns like `rotating:9000,batches:2`.
+pub fn crane(spec: &str) -> Result<Box<dyn Crane>, String> {
+    let number = |value: &str| {
+        value
+            .parse::<usize>()
+            .map_err(|_| format!("invalid number of crates `{}`", value))
+    };
+
+    match spec.split_once(':') {
+        None if spec == "9000" => Ok(Box::new(CrateMover9000)),
+        None if spec == "9001" => Ok(Box::new(CrateMover9001)),
+        Some(("capacity", value)) => match number(value)? {
+            0 => Err("capacity of no crate".into()),
+            max => Ok(Box::new(Capacity(max))),
+        },
+        Some(("batches", value)) => match number(value)? {
+            0 => Err("batches of no crate".into()),
+            size => Ok(Box::new(Batches(size))),
+        },
+        Some(("rotating", value)) => Ok(Box::new(Rotating(
+            value.split(',').map(crane).collect::<Result<_, _>>()?,
+        ))),
+        _ => Err(format!(
+            "unknown crane `{}`, expected `9000`, `9001`, `capacity:N`, `batches:N` or `rotating:A,B`",
+            spec
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crane::{
+        crane, lift, Batches, Capacity, Crane, CrateMover9000, CrateMover9001, Rotating,
+    };
+
+    #[test]
+    fn lifting() {
+        let mut stacks = vec![vec!["A".into(), "B".into(), "C".into()], vec![]];
+        lift(&mut stacks, 0, 1, 2);
+        assert_eq!(vec![vec!["A"], vec!["B", "C"]], stacks);
+        lift(&mut stacks, 0, 1, 5);
+        assert!(stacks[0].is_empty());
+        assert_eq!(vec!["B", "C", "A"], stacks[1]);
+    }
+
+    #[test]
+    fn models() {
+        assert_eq!(vec![1, 1, 1], CrateMover9000.lifts(0, 3));
+        assert_eq!(vec![3], CrateMover9001.lifts(0, 3));
+        assert_eq!(vec![3], Capacity(3).lifts(0, 3));
+        assert_eq!(vec![3, 1], Capacity(3).lifts(0, 4));
+        assert_eq!(vec![2, 2, 2], Capacity(2).lifts(0, 6));
+        assert_eq!(vec![2, 2, 1], Batches(2).lifts(0, 5));
+        assert_eq!(Vec::<usize>::new(), Batches(2).lifts(0, 0));
+
+        let rotating = Rotating(vec![Box::new(CrateMover9000), Box::new(CrateMover9001)]);
+        assert_eq!(vec![1, 1], rotating.lifts(0, 2));
+        assert_eq!(vec![2], rotating.lifts(1, 2));
+        assert_eq!(vec![1, 1], rotating.lifts(2, 2));
+    }
+
+    #[test]
+    fn specs() {
+        assert_eq!("CrateMover 9001", crane("9001").unwrap().to_string());
+        assert_eq!("capacity 4", crane("capacity:4").unwrap().to_string());
+        assert_eq!(
+            "rotating CrateMover 9000, batches of 2",
+            crane("rotating:9000,batches:2").unwrap().to_string()
+        );
+        assert_eq!(
+            Some("invalid number of crates `x`".into()),
+            crane("batches:x").err()
+        );
+        assert_eq!(Some("batches of no crate".into()), crane("batches:0").err());
+        assert_eq!(
+            Some("capacity of no crate".into()),
+            crane("capacity:0").err()
+        );
+        assert!(crane("9002").is_err());
+    }
+}
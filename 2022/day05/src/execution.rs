@@ -0,0 +1,101 @@
+//! What can go wrong when applying instructions, and what to do then.
+
+use std::fmt;
+
+/// Why an instruction cannot be applied as written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// Stack number, from 1, beyond the last stack
+    UnknownStack(usize),
+    /// Stack number, from 1, of both the source and the destination
+    SameStack(usize),
+    /// Source stack, from 1, holding fewer crates than the ones to move
+    InsufficientCrates {
+        stack: usize,
+        wanted: usize,
+        available: usize,
+    },
+}
+
+/// Instruction that cannot be applied, along with its line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutionError {
+    pub line: usize,
+    pub kind: ErrorKind,
+}
+
+impl fmt::Display for ExecutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match self.kind {
+            ErrorKind::UnknownStack(stack) => write!(f, "no stack {}", stack),
+            ErrorKind::SameStack(stack) => {
+                write!(f, "moving crates from stack {} onto itself", stack)
+            }
+            ErrorKind::InsufficientCrates {
+                stack,
+                wanted,
+                available,
+            } => write!(
+                f,
+                "moving {} crates from stack {}, which holds {}",
+                wanted, stack, available
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ExecutionError {}
+
+/// How instructions that cannot be applied as written are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Validation {
+    /// Skips unknown or identical stacks and moves the crates there are,
+    /// keeping a warning for each
+    Lenient,
+    /// Stops at the first of them
+    Strict,
+}
+
+/// Stacks once every instruction is applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rearrangement {
+    /// Crates of each stack, bottom first
+    pub stacks: Vec<Vec<String>>,
+    /// Instructions not applied as written, in lenient mode
+    pub warnings: Vec<ExecutionError>,
+}
+
+impl Rearrangement {
+    /// Crate on top of each stack, nothing for empty ones.
+    pub fn top(&self) -> String {
+        self.stacks
+            .iter()
+            .filter_map(|it| it.last())
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execution::{ErrorKind, ExecutionError};
+
+    #[test]
+    fn display() {
+        let error = |kind| ExecutionError { line: 7, kind }.to_string();
+        assert_eq!("line 7: no stack 4", error(ErrorKind::UnknownStack(4)));
+        assert_eq!(
+            "line 7: moving crates from stack 2 onto itself",
+            error(ErrorKind::SameStack(2))
+        );
+        assert_eq!(
+            "line 7: moving 3 crates from stack 1, which holds 2",
+            error(ErrorKind::InsufficientCrates {
+                stack: 1,
+                wanted: 3,
+                available: 2
+            })
+        );
+    }
+}
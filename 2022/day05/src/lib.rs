@@ -0,0 +1,583 @@
+use aoc_common::{parser, ParseError, Result, Solution, Span};
+
+mod crane;
+mod execution;
+
+use crane::lift;
+pub use crane::{crane, Batches, Capacity, Crane, CrateMover9000, CrateMover9001, Rotating};
+pub use execution::{ErrorKind, ExecutionError, Rearrangement, Validation};
+
+#[derive(Debug, PartialEq)]
+pub struct Instruction {
+    line: usize,
+    qty: usize,
+    from: usize,
+    to: usize,
+}
+
+fn parse_stack_index(word: &Span) -> Result<usize> {
+    word.parse::<usize>("a stack number")?
+        .checked_sub(1)
+        .ok_or_else(|| word.error("a stack number starting at 1"))
+}
+
+impl TryFrom<Span<'_>> for Instruction {
+    type Error = ParseError;
+
+    fn try_from(line: Span) -> Result<Self> {
+        line.word(0, "`move`")?.expect("move")?;
+        let qty = line.word(1, "a quantity")?.parse::<usize>("a quantity")?;
+        line.word(2, "`from`")?.expect("from")?;
+        let from = parse_stack_index(&line.word(3, "a stack number")?)?;
+        line.word(4, "`to`")?.expect("to")?;
+        let to = parse_stack_index(&line.word(5, "a stack number")?)?;
+        line.end_after(6)?;
+
+        Ok(Self {
+            line: line.line,
+            qty,
+            from,
+            to,
+        })
+    }
+}
+
+impl Instruction {
+    fn error(&self, kind: ErrorKind) -> ExecutionError {
+        ExecutionError {
+            line: self.line,
+            kind,
+        }
+    }
+
+    /// Checks that the stacks exist, differ, and hold enough crates.
+    fn check(&self, stacks: &[Vec<String>]) -> std::result::Result<(), ExecutionError> {
+        if let Some(stack) = [self.from, self.to]
+            .into_iter()
+            .find(|it| *it >= stacks.len())
+        {
+            return Err(self.error(ErrorKind::UnknownStack(stack + 1)));
+        }
+        if self.from == self.to {
+            return Err(self.error(ErrorKind::SameStack(self.from + 1)));
+        }
+        let available = stacks[self.from].len();
+        if available < self.qty {
+            return Err(self.error(ErrorKind::InsufficientCrates {
+                stack: self.from + 1,
+                wanted: self.qty,
+                available,
+            }));
+        }
+        Ok(())
+    }
+
+    /// Moves the crates the way `crane` does, this being instruction `step`,
+    /// as many as there are.
+    fn execute(&self, crane: &dyn Crane, step: usize, stacks: &mut [Vec<String>]) {
+        for count in crane.lifts(step, self.qty) {
+            lift(stacks, self.from, self.to, count);
+        }
+    }
+}
+
+/// Starting crate stacks, bottom first, and the moves to apply on them.
+pub struct Procedure {
+    stacks: Vec<Vec<String>>,
+    instructions: Vec<Instruction>,
+    validation: Validation,
+}
+
+/// Where the instruction at `line` goes wrong, as a parse error pointing at
+/// the offending number.
+fn locate(lines: &[Span], err: &ExecutionError, count: usize) -> ParseError {
+    let Some(line) = lines.iter().find(|it| it.line == err.line) else {
+        return ParseError::new(err.line, 1, "an instruction that can be applied");
+    };
+    let word = |idx| line.words()[idx];
+    match err.kind {
+        ErrorKind::UnknownStack(stack) => {
+            // The source is checked first
+            let idx = match word(3).text.parse() == Ok(stack) {
+                true => 3,
+                false => 5,
+            };
+            word(idx).error(format!("a stack number up to {}", count))
+        }
+        ErrorKind::SameStack(_) => word(5).error("a stack other than the source"),
+        ErrorKind::InsufficientCrates { available, .. } => {
+            word(1).error(format!("at most {} crates", available))
+        }
+    }
+}
+
+impl Procedure {
+    /// Reads the drawing and the instructions, making sure in strict mode that
+    /// every instruction can be applied as written.
+    pub fn parse(input: &str, validation: Validation) -> Result<Self> {
+        let mut it = parser::lines(input);
+        let stacks = parse_stacks(&mut it)?;
+        let lines = it.collect::<Vec<_>>();
+        let instructions = parse_instructions(&mut lines.iter().copied())?;
+
+        let procedure = Procedure {
+            stacks,
+            instructions,
+            validation,
+        };
+        // Stack heights, hence errors, do not depend on the crane
+        procedure
+            .run(&CrateMover9000, validation)
+            .map_err(|err| locate(&lines, &err, procedure.stacks.len()))?;
+        Ok(procedure)
+    }
+
+    /// Applies every instruction with `crane`, failing on the first one that
+    /// cannot be applied as written in strict mode.
+    pub fn run(
+        &self,
+        crane: &dyn Crane,
+        validation: Validation,
+    ) -> std::result::Result<Rearrangement, ExecutionError> {
+        let mut stacks = self.stacks.to_vec();
+        let mut warnings = vec![];
+
+        for (step, instru) in self.instructions.iter().enumerate() {
+            if let Err(err) = instru.check(&stacks) {
+                if validation == Validation::Strict {
+                    return Err(err);
+                }
+                warnings.push(err);
+                if !matches!(err.kind, ErrorKind::InsufficientCrates { .. }) {
+                    continue;
+                }
+            }
+            instru.execute(crane, step, &mut stacks);
+        }
+
+        Ok(Rearrangement { stacks, warnings })
+    }
+
+    /// Top crates once `crane` applied every instruction, in the mode the
+    /// procedure was parsed with.
+    pub fn rearrange(&self, crane: &dyn Crane) -> String {
+        self.run(crane, self.validation)
+            .expect("instructions are checked when parsing")
+            .top()
+    }
+}
+
+/// Reads one `[A] ` cell of the drawing, empty when no crate is there.
+fn parse_crate(cell: &[Span]) -> Result<String> {
+    let text = cell.iter().take(3).map(|it| it.text).collect::<String>();
+    let letter = text.chars().nth(1).unwrap_or(' ');
+
+    if let Some(separator) = cell.get(3).filter(|it| it.text != " ") {
+        return Err(separator.error("a space between crates"));
+    }
+    if text == "   " {
+        Ok("".into())
+    } else if text.len() == 3 && text.starts_with('[') && text.ends_with(']') && letter != ' ' {
+        Ok(letter.to_string())
+    } else {
+        Err(ParseError::new(cell[0].line, cell[0].column, "a crate like `[A]`").found(text))
+    }
+}
+
+/// Reads a row of the drawing, with at most `count` stacks.
+fn parse_row(row: &Span, count: usize) -> Result<Vec<String>> {
+    let chars = row.chars();
+    let mut res = vec![];
+    for (idx, cell) in chars.chunks(4).enumerate() {
+        let item = parse_crate(cell)?;
+        if idx >= count && !item.is_empty() {
+            let text = cell.iter().take(3).map(|it| it.text).collect::<String>();
+            let expected = format!("no crate beyond stack {}", count);
+            return Err(ParseError::new(cell[0].line, cell[0].column, expected).found(text));
+        }
+        res.push(item);
+    }
+    Ok(res)
+}
+
+/**
+ * In charge of reading lines which contains crates
+ * and stopping when getting a blank line
+ * Iterator is updated for instructions reading
+ */
+fn parse_stacks<'a, I>(iterator: &mut I) -> Result<Vec<Vec<String>>>
+where
+    I: Iterator<Item = Span<'a>>,
+{
+    let mut stack_str: Vec<Span> = Vec::new();
+    for line in iterator.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+        stack_str.push(line);
+    }
+
+    // The line of numbers gives the stack count
+    let numbers = match stack_str.pop() {
+        Some(line) => line,
+        None => return Err(ParseError::new(1, 1, "a drawing of the stacks")),
+    };
+    for (idx, number) in numbers.words().iter().enumerate() {
+        number.expect(&(idx + 1).to_string())?;
+    }
+
+    // For each line build the items in place
+    let count = numbers.words().len();
+    let mut res = stack_str
+        .iter()
+        .map(|it| parse_row(it, count))
+        .collect::<Result<Vec<Vec<String>>>>()?;
+
+    let mut stacks = vec![Vec::<String>::new(); count];
+
+    while let Some(line) = res.pop() {
+        for (stack, el) in stacks.iter_mut().zip(line) {
+            if !el.is_empty() {
+                stack.push(el);
+            }
+        }
+    }
+
+    Ok(stacks)
+}
+
+fn parse_instructions<'a, I>(iterator: &mut I) -> Result<Vec<Instruction>>
+where
+    I: Iterator<Item = Span<'a>>,
+{
+    iterator.map(Instruction::try_from).collect()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Procedure;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Procedure::parse(input, Validation::Strict)
+    }
+
+    fn part1(procedure: &Self::Input) -> String {
+        procedure.rearrange(&CrateMover9000)
+    }
+
+    fn part2(procedure: &Self::Input) -> String {
+        procedure.rearrange(&CrateMover9001)
+    }
+}
+
+/// Same as [`Day05`], applying what it can of the instructions that cannot be
+/// applied as written (see [`Validation::Lenient`]).
+pub struct Day05Lenient;
+
+impl Solution for Day05Lenient {
+    type Input = Procedure;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Procedure::parse(input, Validation::Lenient)
+    }
+
+    fn part1(procedure: &Self::Input) -> String {
+        Day05::part1(procedure)
+    }
+
+    fn part2(procedure: &Self::Input) -> String {
+        Day05::part2(procedure)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        crane, parse_instructions, parse_stacks, Capacity, CrateMover9000, CrateMover9001, Day05,
+        Day05Lenient, ErrorKind, ExecutionError, Instruction, Validation,
+    };
+    use aoc_common::{input, parser, ParseError, Solution};
+
+    #[test]
+    fn example() {
+        let raw = input::raw("tests/example.txt");
+
+        let mut it = parser::lines(&raw);
+        let mut stacks = parse_stacks(&mut it).unwrap();
+        println!("{:?}", stacks);
+
+        assert_eq!(vec!["Z", "N"], *stacks.first().unwrap());
+        assert_eq!(vec!["M", "C", "D"], *stacks.get(1).unwrap());
+        assert_eq!(vec!["P"], *stacks.get(2).unwrap());
+
+        (Instruction {
+            line: 6,
+            qty: 1,
+            from: 1,
+            to: 0,
+        })
+        .execute(&CrateMover9000, 0, &mut stacks);
+        println!("{:?}", stacks);
+
+        assert_eq!(vec!["Z", "N", "D"], *stacks.first().unwrap());
+        assert_eq!(vec!["M", "C"], *stacks.get(1).unwrap());
+        assert_eq!(vec!["P"], *stacks.get(2).unwrap());
+
+        (Instruction {
+            line: 7,
+            qty: 3,
+            from: 0,
+            to: 2,
+        })
+        .execute(&CrateMover9000, 0, &mut stacks);
+        println!("{:?}", stacks);
+
+        assert_eq!(Vec::<String>::new(), *stacks.first().unwrap());
+        assert_eq!(vec!["M", "C"], *stacks.get(1).unwrap());
+        assert_eq!(vec!["P", "D", "N", "Z"], *stacks.get(2).unwrap());
+
+        (Instruction {
+            line: 8,
+            qty: 2,
+            from: 1,
+            to: 0,
+        })
+        .execute(&CrateMover9000, 0, &mut stacks);
+        println!("{:?}", stacks);
+
+        assert_eq!(vec!["C", "M"], *stacks.first().unwrap());
+        assert_eq!(Vec::<String>::new(), *stacks.get(1).unwrap());
+        assert_eq!(vec!["P", "D", "N", "Z"], *stacks.get(2).unwrap());
+
+        (Instruction {
+            line: 9,
+            qty: 1,
+            from: 0,
+            to: 1,
+        })
+        .execute(&CrateMover9000, 0, &mut stacks);
+        println!("{:?}", stacks);
+
+        assert_eq!(vec!["C"], *stacks.first().unwrap());
+        assert_eq!(vec!["M"], *stacks.get(1).unwrap());
+        assert_eq!(vec!["P", "D", "N", "Z"], *stacks.get(2).unwrap());
+
+        let instructions = parse_instructions(&mut it).unwrap();
+        println!("{:?}", instructions);
+
+        assert_eq!(
+            vec![
+                Instruction {
+                    line: 6,
+                    qty: 1,
+                    from: 1,
+                    to: 0
+                },
+                Instruction {
+                    line: 7,
+                    qty: 3,
+                    from: 0,
+                    to: 2
+                },
+                Instruction {
+                    line: 8,
+                    qty: 2,
+                    from: 1,
+                    to: 0
+                },
+                Instruction {
+                    line: 9,
+                    qty: 1,
+                    from: 0,
+                    to: 1
+                }
+            ],
+            instructions
+        )
+    }
+
+    #[test]
+    fn full_example() {
+        let raw = input::raw("tests/example.txt");
+        let mut it = parser::lines(&raw);
+        let mut stacks = parse_stacks(&mut it).unwrap();
+        let instructions = parse_instructions(&mut it).unwrap();
+
+        assert_eq!(vec!["Z", "N"], *stacks.first().unwrap());
+        assert_eq!(vec!["M", "C", "D"], *stacks.get(1).unwrap());
+        assert_eq!(vec!["P"], *stacks.get(2).unwrap());
+
+        instructions
+            .first()
+            .unwrap()
+            .execute(&CrateMover9000, 0, &mut stacks);
+
+        assert_eq!(vec!["Z", "N", "D"], *stacks.first().unwrap());
+        assert_eq!(vec!["M", "C"], *stacks.get(1).unwrap());
+        assert_eq!(vec!["P"], *stacks.get(2).unwrap());
+
+        instructions
+            .get(1)
+            .unwrap()
+            .execute(&CrateMover9000, 0, &mut stacks);
+
+        assert_eq!(Vec::<String>::new(), *stacks.first().unwrap());
+        assert_eq!(vec!["M", "C"], *stacks.get(1).unwrap());
+        assert_eq!(vec!["P", "D", "N", "Z"], *stacks.get(2).unwrap());
+
+        instructions
+            .get(2)
+            .unwrap()
+            .execute(&CrateMover9000, 0, &mut stacks);
+
+        assert_eq!(vec!["C", "M"], *stacks.first().unwrap());
+        assert_eq!(Vec::<String>::new(), *stacks.get(1).unwrap());
+        assert_eq!(vec!["P", "D", "N", "Z"], *stacks.get(2).unwrap());
+
+        instructions
+            .get(3)
+            .unwrap()
+            .execute(&CrateMover9000, 0, &mut stacks);
+
+        assert_eq!(vec!["C"], *stacks.first().unwrap());
+        assert_eq!(vec!["M"], *stacks.get(1).unwrap());
+        assert_eq!(vec!["P", "D", "N", "Z"], *stacks.get(2).unwrap());
+    }
+
+    #[test]
+    fn solution() {
+        let procedure = Day05::parse(&input::raw("tests/example.txt")).unwrap();
+        assert_eq!("CMZ", Day05::part1(&procedure));
+        assert_eq!("MCD", Day05::part2(&procedure));
+    }
+
+    #[test]
+    fn cranes() {
+        let procedure = Day05::parse(&input::raw("tests/example.txt")).unwrap();
+        let rearrange = |spec| procedure.rearrange(crane(spec).unwrap().as_ref());
+        assert_eq!("CMZ", rearrange("capacity:1"));
+        assert_eq!("MCD", rearrange("capacity:3"));
+        // Moving `Z N D` as `N D` then `Z`, at most two crates at once
+        let capacity = procedure.run(&Capacity(2), Validation::Strict).unwrap();
+        assert_eq!(vec!["M"], capacity.stacks[0]);
+        assert_eq!(vec!["C"], capacity.stacks[1]);
+        assert_eq!(vec!["P", "N", "D", "Z"], capacity.stacks[2]);
+        assert_eq!("MCD", rearrange("batches:5"));
+        // Moving `Z N D` as `N D` then `Z`, which ends up on top
+        assert_eq!("MCZ", rearrange("batches:2"));
+        // As in part 2, except `M C` being moved one at a time
+        assert_eq!("CMD", rearrange("rotating:9000,9001"));
+    }
+
+    #[test]
+    fn execution() {
+        let stacks = "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n\n";
+        let raw = format!(
+            "{}move 1 from 2 to 4\nmove 2 from 3 to 3\nmove 3 from 3 to 1\nmove 1 from 1 to 2",
+            stacks
+        );
+        let procedure = Day05Lenient::parse(&raw).unwrap();
+
+        assert_eq!(
+            Err(ExecutionError {
+                line: 6,
+                kind: ErrorKind::UnknownStack(4)
+            }),
+            procedure.run(&CrateMover9000, Validation::Strict)
+        );
+
+        let lenient = procedure.run(&CrateMover9000, Validation::Lenient).unwrap();
+        assert_eq!(
+            vec![
+                "line 6: no stack 4",
+                "line 7: moving crates from stack 3 onto itself",
+                "line 8: moving 3 crates from stack 3, which holds 1",
+            ],
+            lenient
+                .warnings
+                .iter()
+                .map(|it| it.to_string())
+                .collect::<Vec<_>>()
+        );
+        // Only `P` moves to stack 1, and then to stack 2
+        assert_eq!("NP", lenient.top());
+        assert_eq!("NP", procedure.rearrange(&CrateMover9000));
+
+        // Strict parsing points at the first offending number
+        assert_eq!(
+            Some(ParseError::new(6, 18, "a stack number up to 3").found("4")),
+            Day05::parse(&raw).err()
+        );
+        let parse = |instruction| Day05::parse(&format!("{}{}", stacks, instruction)).err();
+        assert_eq!(
+            Some(ParseError::new(6, 13, "a stack number up to 3").found("5")),
+            parse("move 1 from 5 to 1")
+        );
+        assert_eq!(
+            Some(ParseError::new(6, 18, "a stack other than the source").found("3")),
+            parse("move 1 from 3 to 3")
+        );
+        assert_eq!(
+            Some(ParseError::new(6, 6, "at most 2 crates").found("3")),
+            parse("move 3 from 1 to 3")
+        );
+
+        let procedure = Day05::parse(&input::raw("tests/example.txt")).unwrap();
+        let strict = procedure.run(&CrateMover9001, Validation::Strict).unwrap();
+        assert!(strict.warnings.is_empty());
+        assert_eq!("MCD", strict.top());
+    }
+
+    #[test]
+    fn line_endings() {
+        let expected = Day05::parse(&input::raw("tests/example.txt")).unwrap();
+        for path in ["tests/example_crlf.txt", "tests/example_newline.txt"] {
+            let parsed = Day05::parse(&input::raw(path)).unwrap();
+            assert_eq!(Day05::part1(&expected), Day05::part1(&parsed));
+            assert_eq!(Day05::part2(&expected), Day05::part2(&parsed));
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let stacks = "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n\n";
+
+        assert_eq!(
+            Some(ParseError::new(6, 18, "a stack number").found("x")),
+            Day05::parse(&format!("{}move 1 from 2 to x", stacks)).err()
+        );
+        assert_eq!(
+            Some(ParseError::new(6, 8, "`from`").found("form")),
+            Day05::parse(&format!("{}move 1 form 2 to 1", stacks)).err()
+        );
+        assert_eq!(
+            Some(ParseError::new(6, 13, "a stack number starting at 1").found("0")),
+            Day05::parse(&format!("{}move 1 from 0 to 1", stacks)).err()
+        );
+        assert_eq!(
+            Some(ParseError::new(6, 14, "`to`")),
+            Day05::parse(&format!("{}move 1 from 2", stacks)).err()
+        );
+        assert_eq!(
+            Some(ParseError::new(2, 5, "a crate like `[A]`").found("(C)")),
+            Day05::parse("    [D]\n[N] (C)\n 1   2\n\nmove 1 from 2 to 1").err()
+        );
+        assert_eq!(
+            Some(ParseError::new(1, 5, "no crate beyond stack 1").found("[B]")),
+            Day05::parse("[A] [B]\n 1\n\n").err()
+        );
+        assert_eq!(
+            Some(ParseError::new(1, 9, "no crate beyond stack 2").found("[C]")),
+            Day05::parse("[A] [B] [C]\n 1   2\n\nmove 1 from 1 to 2").err()
+        );
+        assert_eq!(
+            Some(ParseError::new(1, 4, "a space between crates").found("é")),
+            Day05::parse("[A]é\n 1\n\n").err()
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use aoc2022_day05::{crane, CrateMover9000, Day05, Day05Lenient, Validation};
+use aoc_common::{solution, Solution};
+
+fn main() {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let cranes = solution::take_options(&mut args, "-c", "--crane")
+        .and_then(|it| it.iter().map(|it| crane(it)).collect::<Result<Vec<_>, _>>())
+        .unwrap_or_else(|err| solution::fail(&err));
+    let lenient = solution::take_switch(&mut args, "-l", "--lenient");
+    // The input is picked from the remaining arguments
+    let default = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/myinput.txt");
+    let parsed = match lenient {
+        true => solution::load_from::<Day05Lenient, _>(args.into_iter(), default),
+        // Instructions that cannot be applied are reported with their line
+        false => solution::load_from::<Day05, _>(args.into_iter(), default),
+    };
+
+    if lenient {
+        // Stack heights, hence warnings, do not depend on the crane
+        let rearranged = parsed.run(&CrateMover9000, Validation::Lenient);
+        for warning in rearranged.iter().flat_map(|it| it.warnings.iter()) {
+            eprintln!("warning: {}", warning);
+        }
+    }
+    println!("Result: {}", Day05::part1(&parsed));
+    println!("Result (part 2): {}", Day05::part2(&parsed));
+    for crane in cranes {
+        println!("Result ({}): {}", crane, parsed.rearrange(crane.as_ref()));
+    }
+}
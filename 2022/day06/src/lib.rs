@@ -0,0 +1,110 @@
+use aoc_common::{parser, Result, Solution};
+use std::collections::{BTreeSet, VecDeque};
+
+fn has_unique_elements<T>(iter: T) -> bool
+where
+    T: IntoIterator,
+    T::Item: Eq + Ord,
+{
+    let mut uniq = BTreeSet::new();
+    iter.into_iter().all(move |x| uniq.insert(x))
+}
+
+fn find_start_of_packet<T: Into<String>>(input: T, distinct: usize) -> usize {
+    let str: String = input.into();
+    let tbl = VecDeque::from(str.as_bytes().to_vec());
+
+    let mut search = 0;
+    loop {
+        if search + distinct < tbl.len() {
+            if has_unique_elements(tbl.range(search..(search + 1 + distinct))) {
+                return search + distinct;
+            } else {
+                search += 1;
+            }
+        } else {
+            return usize::MAX;
+        }
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut lines = parser::lines(input);
+        let signal = lines.next().map_or("", |it| it.text);
+        match lines.next() {
+            Some(extra) => Err(extra.error("end of input")),
+            None => Ok(signal.into()),
+        }
+    }
+
+    fn part1(signal: &Self::Input) -> usize {
+        find_start_of_packet(signal, 4)
+    }
+
+    fn part2(signal: &Self::Input) -> usize {
+        find_start_of_packet(signal, 14)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{find_start_of_packet, Day06};
+    use aoc_common::{input, Solution};
+
+    #[test]
+    fn example() {
+        assert_eq!(7, find_start_of_packet("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 4));
+        assert_eq!(5, find_start_of_packet("bvwbjplbgvbhsrlpgdmjqwftvncz", 4));
+        assert_eq!(6, find_start_of_packet("nppdvjthqldpwncqszvftbrmjlhg", 4));
+        assert_eq!(
+            10,
+            find_start_of_packet("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 4)
+        );
+        assert_eq!(
+            11,
+            find_start_of_packet("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 4)
+        );
+    }
+
+    #[test]
+    fn example_pt2() {
+        assert_eq!(
+            25,
+            find_start_of_packet("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14)
+        );
+        assert_eq!(23, find_start_of_packet("bvwbjplbgvbhsrlpgdmjqwftvncz", 14));
+        assert_eq!(23, find_start_of_packet("nppdvjthqldpwncqszvftbrmjlhg", 14));
+        assert_eq!(
+            29,
+            find_start_of_packet("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14)
+        );
+        assert_eq!(
+            26,
+            find_start_of_packet("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 14)
+        );
+    }
+
+    #[test]
+    fn solution() {
+        let signal = Day06::parse("mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap();
+        assert_eq!(7, Day06::part1(&signal));
+        assert_eq!(25, Day06::part2(&signal));
+    }
+
+    #[test]
+    fn line_endings() {
+        let expected = Day06::parse(&input::raw("tests/example.txt")).unwrap();
+        for path in ["tests/example_crlf.txt", "tests/example_newline.txt"] {
+            let parsed = Day06::parse(&input::raw(path)).unwrap();
+            assert_eq!(Day06::part1(&expected), Day06::part1(&parsed));
+            assert_eq!(Day06::part2(&expected), Day06::part2(&parsed));
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use aoc2022_day06::Day06;
+use aoc_common::{solution, Solution};
+
+fn main() {
+    let parsed = solution::load::<Day06>(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/myinput.txt"));
+    println!("Result: {}", Day06::part1(&parsed));
+    println!("Result (part 2): {}", Day06::part2(&parsed));
+}
@@ -0,0 +1,280 @@
+use aoc_common::{parser, ParseError, Solution, Span};
+use std::vec::IntoIter;
+
+#[derive(Debug, Clone)]
+pub enum Node {
+    Dir { name: String, children: Vec<Node> },
+    File { name: String, size: i32 },
+}
+
+impl Node {
+    pub fn get_name(&self) -> String {
+        match self {
+            Self::File { name, .. } => name.clone(),
+            Self::Dir { name, .. } => name.clone(),
+        }
+    }
+
+    fn get_size(&self) -> i32 {
+        match self {
+            Self::File { size, .. } => *size,
+            Self::Dir { children, .. } => children.iter().fold(0, |acc, el| acc + el.get_size()),
+        }
+    }
+
+    fn iter(&self) -> IntoIter<Node> {
+        let mut res: Vec<Node> = vec![self.clone()];
+
+        if let Node::Dir { children, .. } = self {
+            res.extend(children.iter().flat_map(|it| Self::into_iter(it.clone())))
+        }
+
+        res.into_iter()
+    }
+}
+
+impl IntoIterator for Node {
+    type Item = Node;
+
+    type IntoIter = IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let rself = &self;
+        let mut res: Vec<Node> = vec![rself.clone()];
+
+        if let Node::Dir { children, .. } = rself {
+            res.extend(children.iter().flat_map(|it| Self::into_iter(it.clone())))
+        }
+
+        res.into_iter()
+    }
+}
+
+impl Default for Node {
+    fn default() -> Self {
+        Self::Dir {
+            name: Default::default(),
+            children: Default::default(),
+        }
+    }
+}
+
+impl TryFrom<Span<'_>> for Node {
+    type Error = ParseError;
+
+    fn try_from(value: Span) -> Result<Self, Self::Error> {
+        let kind = value.word(0, "a file size or `dir`")?;
+        let name = String::from(value.word(1, "a name")?.text);
+        value.end_after(2)?;
+
+        if kind.text == "dir" {
+            Ok(Node::Dir {
+                name,
+                children: vec![],
+            })
+        } else {
+            Ok(Node::File {
+                name,
+                size: kind.parse::<i32>("a file size or `dir`")?,
+            })
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Command {
+    Cd(String),
+    CdParent,
+    Ls(Vec<Node>),
+}
+
+fn parse_commands<'a, T>(lines: T) -> Result<Vec<Command>, ParseError>
+where
+    T: Iterator<Item = Span<'a>>,
+{
+    let mut reader = lines.peekable();
+    let mut commands = Vec::<Command>::new();
+
+    while let Some(elt) = reader.next() {
+        elt.word(0, "a `$` command")?.expect("$")?;
+        commands.push(match elt.word(1, "`cd` or `ls`")?.one_of(&["cd", "ls"])? {
+            0 => {
+                let folder = elt.word(2, "a folder")?;
+                elt.end_after(3)?;
+                match folder.text {
+                    ".." => Command::CdParent,
+                    folder => Command::Cd(String::from(folder)),
+                }
+            }
+            _ => {
+                elt.end_after(2)?;
+                let mut result = Vec::<Node>::new();
+
+                while let Some(line) = reader.next_if(|it| !it.text.starts_with('$')) {
+                    let node = Node::try_from(line)?;
+                    // Directories are only built when entering them
+                    if matches!(node, Node::File { .. }) {
+                        result.push(node);
+                    }
+                }
+
+                Command::Ls(result)
+            }
+        })
+    }
+
+    Ok(commands)
+}
+
+fn build_tree(commands: &[Command]) -> Result<Node, ParseError> {
+    build_children(&mut commands.iter())
+        .first()
+        .cloned()
+        .ok_or_else(|| ParseError::new(1, 1, "a `$ cd` command"))
+}
+
+fn build_children<'a, T>(reader: &mut T) -> Vec<Node>
+where
+    T: Iterator<Item = &'a Command>,
+{
+    // let mut reader = commands.iter().skip(start_at);
+    let mut children = Vec::<Node>::new();
+
+    while let Some(command) = reader.next() {
+        match command {
+            Command::Cd(folder_name) => children.push(Node::Dir {
+                name: String::from(folder_name),
+                children: build_children(reader),
+            }),
+            Command::Ls(result) => children.extend(result.to_vec()),
+            _ => return children,
+        }
+    }
+
+    children
+}
+
+fn sum_directories_max_size<T>(it: &mut T, max: i32) -> i32
+where
+    T: Iterator<Item = Node>,
+{
+    it
+        // Only keep dirs
+        .filter(|it| matches!(it, Node::Dir { .. }))
+        // Get their size
+        .map(|it| it.get_size())
+        // Keep only those of max size
+        .filter(|it| it <= &max)
+        // Sum it
+        .sum()
+}
+
+fn pick_directory_to_delete(root: &Node, fs_size: i32, space_needed: i32) -> i32 {
+    let total_space = root.get_size();
+    let free_space = fs_size - total_space;
+    let min_delete = space_needed - free_space;
+
+    root.iter()
+        // Only keep dirs
+        .filter(|it| matches!(it, Node::Dir { .. }))
+        // Get their size
+        .map(|it| it.get_size())
+        // Keep only those freeing enough space
+        .filter(|it| it >= &min_delete)
+        // Get smallest
+        .fold(total_space, std::cmp::min)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Node;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        build_tree(&parse_commands(parser::lines(input))?)
+    }
+
+    fn part1(root: &Self::Input) -> i32 {
+        sum_directories_max_size(&mut root.iter(), 100000)
+    }
+
+    fn part2(root: &Self::Input) -> i32 {
+        pick_directory_to_delete(root, 70000000, 30000000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        build_tree, parse_commands, pick_directory_to_delete, sum_directories_max_size, Day07, Node,
+    };
+    use aoc_common::{input, parser, ParseError, Solution};
+
+    #[test]
+    fn example() {
+        let raw = input::raw("tests/example.txt");
+        let commands = parse_commands(parser::lines(&raw)).unwrap();
+        let children = build_tree(&commands).unwrap();
+
+        println!("{:#?}", children);
+
+        println!("{:#?}", children.iter());
+
+        assert_eq!(
+            4,
+            children
+                .iter()
+                .filter(|it| matches!(it, Node::Dir { .. }))
+                .count()
+        );
+
+        assert_eq!(
+            95437,
+            sum_directories_max_size(&mut children.iter(), 100000)
+        );
+
+        assert_eq!(
+            24933642,
+            pick_directory_to_delete(&children, 70000000, 30000000)
+        );
+    }
+
+    #[test]
+    fn solution() {
+        let root = Day07::parse(&input::raw("tests/example.txt")).unwrap();
+        assert_eq!(95437, Day07::part1(&root));
+        assert_eq!(24933642, Day07::part2(&root));
+    }
+
+    #[test]
+    fn line_endings() {
+        let expected = Day07::parse(&input::raw("tests/example.txt")).unwrap();
+        for path in ["tests/example_crlf.txt", "tests/example_newline.txt"] {
+            let parsed = Day07::parse(&input::raw(path)).unwrap();
+            assert_eq!(Day07::part1(&expected), Day07::part1(&parsed));
+            assert_eq!(Day07::part2(&expected), Day07::part2(&parsed));
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Some(ParseError::new(3, 1, "a file size or `dir`").found("12a")),
+            Day07::parse("$ cd /\n$ ls\n12a b.txt").err()
+        );
+        assert_eq!(
+            Some(ParseError::new(2, 3, "`cd` or `ls`").found("rm")),
+            Day07::parse("$ cd /\n$ rm a").err()
+        );
+        assert_eq!(
+            Some(ParseError::new(1, 1, "`$`").found("ls")),
+            Day07::parse("ls").err()
+        );
+        assert_eq!(
+            Some(ParseError::new(1, 1, "a `$ cd` command")),
+            Day07::parse("$ ls").err()
+        );
+    }
+}
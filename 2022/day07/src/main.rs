@@ -0,0 +1,8 @@
+use aoc2022_day07::Day07;
+use aoc_common::{solution, Solution};
+
+fn main() {
+    let parsed = solution::load::<Day07>(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/myinput.txt"));
+    println!("Result: {}", Day07::part1(&parsed));
+    println!("Result (part 2): {}", Day07::part2(&parsed));
+}
@@ -0,0 +1,294 @@
+use aoc_common::{parser, Result, Solution, Span};
+use std::fmt::{self};
+
+pub struct Forest {
+    rows: usize,
+    cols: usize,
+    data: Vec<u8>,
+}
+
+impl Forest {
+    fn new() -> Self {
+        Self {
+            rows: 0,
+            cols: 0,
+            data: Vec::<u8>::new(),
+        }
+    }
+
+    fn push_row(&mut self, row: Vec<u8>) {
+        if self.cols != 0 && row.len() != self.cols {
+            panic!(
+                "row is not matching matrice, trying to add {}, matrice is {}",
+                row.len(),
+                self.cols
+            );
+        }
+
+        if self.cols == 0 {
+            self.cols = row.len();
+        }
+
+        self.data.extend(row);
+        self.rows += 1;
+    }
+
+    fn is_visible(&self, row: usize, col: usize) -> bool {
+        // print!("is_visible {} {}", row, col);
+        if row == 0 || row + 1 == self.rows || col == 0 || col + 1 == self.cols {
+            // println!(" border");
+            true
+        } else {
+            let value = self.data[col + row * self.cols];
+            // println!("  value {}", value);
+
+            let col_v = self.data[col..]
+                .iter()
+                .step_by(self.cols)
+                .collect::<Vec<_>>();
+            let row_v = self.data[row * self.cols..(row + 1) * self.cols]
+                .iter()
+                .collect::<Vec<_>>();
+
+            // println!("  col {:?}  |  {}  |  {:?}", col_v[0..row].iter().collect::<Vec<_>>(), col_v[row], col_v[row+1..self.cols].iter().collect::<Vec<_>>());
+            // println!("  row {:?}  |  {}  |  {:?}", row_v[0..col].iter().collect::<Vec<_>>(), row_v[col], row_v[col+1..self.cols].iter().collect::<Vec<_>>());
+
+            row_v[0..col].iter().all(|it| **it < value)
+                ||
+                // from right
+                row_v[col+1..self.cols].iter().all(|it| **it < value)
+                ||
+                // from top
+                col_v[0..row].iter().all(|it| **it < value)
+                ||
+                // from bottom
+                col_v[row+1..self.rows].iter().all(|it| **it < value)
+        }
+    }
+
+    fn count_visible(&self) -> u32 {
+        (0..self.data.len())
+            .map(|pos| (pos / self.cols, pos % self.cols))
+            .map(|(row, col)| self.is_visible(row, col))
+            .map(|it| if it { 1 } else { 0 })
+            .sum()
+    }
+
+    fn scenic_score(&self, row: usize, col: usize) -> u32 {
+        // print!("is_visible {} {}", row, col);
+        let value = self.data[col + row * self.cols];
+        // println!("  value {}", value);
+
+        let col_v = self.data[col..]
+            .iter()
+            .step_by(self.cols)
+            .collect::<Vec<_>>();
+        let row_v = self.data[row * self.cols..(row + 1) * self.cols]
+            .iter()
+            .collect::<Vec<_>>();
+
+        // println!("  col {:?} ({})  |  {}  |  {:?} ({})", col_v[0..row].iter().collect::<Vec<_>>(), col_v[0..row].iter().rev().fold((true, 0), |(counting, sum), it| if counting {(**it < value, sum+1)} else {(counting, sum)}).1, col_v[row], col_v[row+1..self.cols].iter().collect::<Vec<_>>(), col_v[row+1..self.rows].iter().fold((true, 0), |(counting, sum), it| if counting {(**it < value, sum+1)} else {(counting, sum)}).1);
+        // println!("  row {:?} ({})  |  {}  |  {:?} ({})", row_v[0..col].iter().collect::<Vec<_>>(), row_v[0..col].iter().rev().fold((true, 0), |(counting, sum), it| if counting {(**it < value, sum+1)} else {(counting, sum)}).1, row_v[col], row_v[col+1..self.cols].iter().collect::<Vec<_>>(),row_v[col+1..self.cols].iter().fold((true, 0), |(counting, sum), it| if counting {(**it < value, sum+1)} else {(counting, sum)}).1);
+
+        row_v[0..col]
+            .iter()
+            .rev()
+            .fold((true, 0), |(counting, sum), it| {
+                if counting {
+                    (**it < value, sum + 1)
+                } else {
+                    (counting, sum)
+                }
+            })
+            .1
+            * row_v[col + 1..self.cols]
+                .iter()
+                .fold((true, 0), |(counting, sum), it| {
+                    if counting {
+                        (**it < value, sum + 1)
+                    } else {
+                        (counting, sum)
+                    }
+                })
+                .1
+            * col_v[0..row]
+                .iter()
+                .rev()
+                .fold((true, 0), |(counting, sum), it| {
+                    if counting {
+                        (**it < value, sum + 1)
+                    } else {
+                        (counting, sum)
+                    }
+                })
+                .1
+            * col_v[row + 1..self.rows]
+                .iter()
+                .fold((true, 0), |(counting, sum), it| {
+                    if counting {
+                        (**it < value, sum + 1)
+                    } else {
+                        (counting, sum)
+                    }
+                })
+                .1
+    }
+
+    fn best_scenic_score(&self) -> u32 {
+        (0..self.data.len())
+            .map(|pos| (pos / self.cols, pos % self.cols))
+            .map(|(row, col)| self.scenic_score(row, col))
+            .fold(0, std::cmp::max)
+    }
+}
+
+impl fmt::Debug for Forest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Forest [")?;
+        if self.cols > 0 {
+            if f.alternate() {
+                for (idx, it) in self.data.iter().enumerate() {
+                    if idx % self.cols == 0 {
+                        write!(f, "\n ")?;
+                    }
+                    write!(f, " {}", it)?;
+                }
+            } else {
+                write!(f, "{:?}", self.data)?;
+            }
+        } else {
+            write!(f, " Empty ")?;
+        }
+        write!(f, "\n]")
+    }
+}
+
+fn parse_forest<'a, T>(lines: T) -> Result<Forest>
+where
+    T: IntoIterator<Item = Span<'a>>,
+{
+    let mut forest = Forest::new();
+
+    for line in lines {
+        let trees = line.chars();
+        if trees.is_empty() || (forest.cols != 0 && trees.len() < forest.cols) {
+            return Err(line.end_error(format!("{} tree heights", forest.cols.max(1))));
+        }
+        if forest.cols != 0 && trees.len() > forest.cols {
+            return Err(trees[forest.cols].error("end of line"));
+        }
+
+        forest.push_row(
+            trees
+                .iter()
+                .map(|it| it.parse::<u8>("a tree height"))
+                .collect::<Result<Vec<_>>>()?,
+        );
+    }
+
+    Ok(forest)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Forest;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_forest(parser::lines(input))
+    }
+
+    fn part1(forest: &Self::Input) -> u32 {
+        forest.count_visible()
+    }
+
+    fn part2(forest: &Self::Input) -> u32 {
+        forest.best_scenic_score()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_forest, Day08};
+    use aoc_common::{input, parser, ParseError, Solution};
+
+    #[test]
+    fn example() {
+        let raw = input::raw("tests/example.txt");
+        let forest = parse_forest(parser::lines(&raw)).unwrap();
+
+        println!("{:#?}", forest);
+
+        assert!(forest.is_visible(0, 0));
+        assert!(forest.is_visible(0, 1));
+        assert!(forest.is_visible(0, 2));
+        assert!(forest.is_visible(0, 3));
+        assert!(forest.is_visible(0, 4));
+
+        assert!(forest.is_visible(1, 0));
+        assert!(forest.is_visible(1, 1));
+        assert!(forest.is_visible(1, 2));
+        assert!(!forest.is_visible(1, 3));
+        assert!(forest.is_visible(1, 4));
+
+        assert!(forest.is_visible(2, 0));
+        assert!(forest.is_visible(2, 1));
+        assert!(!forest.is_visible(2, 2));
+        assert!(forest.is_visible(2, 3));
+        assert!(forest.is_visible(2, 4));
+
+        assert!(forest.is_visible(3, 0));
+        assert!(!forest.is_visible(3, 1));
+        assert!(forest.is_visible(3, 2));
+        assert!(!forest.is_visible(3, 3));
+        assert!(forest.is_visible(3, 4));
+
+        assert!(forest.is_visible(4, 0));
+        assert!(forest.is_visible(4, 1));
+        assert!(forest.is_visible(4, 2));
+        assert!(forest.is_visible(4, 3));
+        assert!(forest.is_visible(4, 4));
+
+        assert_eq!(21, forest.count_visible());
+
+        assert_eq!(4, forest.scenic_score(1, 2));
+        assert_eq!(8, forest.scenic_score(3, 2));
+
+        assert_eq!(8, forest.best_scenic_score())
+    }
+
+    #[test]
+    fn solution() {
+        let forest = Day08::parse(&input::raw("tests/example.txt")).unwrap();
+        assert_eq!(21, Day08::part1(&forest));
+        assert_eq!(8, Day08::part2(&forest));
+    }
+
+    #[test]
+    fn line_endings() {
+        let expected = Day08::parse(&input::raw("tests/example.txt")).unwrap();
+        for path in ["tests/example_crlf.txt", "tests/example_newline.txt"] {
+            let parsed = Day08::parse(&input::raw(path)).unwrap();
+            assert_eq!(Day08::part1(&expected), Day08::part1(&parsed));
+            assert_eq!(Day08::part2(&expected), Day08::part2(&parsed));
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Some(ParseError::new(2, 3, "a tree height").found("x")),
+            Day08::parse("123\n45x").err()
+        );
+        assert_eq!(
+            Some(ParseError::new(2, 3, "3 tree heights")),
+            Day08::parse("123\n45").err()
+        );
+        assert_eq!(
+            Some(ParseError::new(2, 4, "end of line").found("7")),
+            Day08::parse("123\n4567").err()
+        );
+    }
+}
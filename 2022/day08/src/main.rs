@@ -0,0 +1,8 @@
+use aoc2022_day08::Day08;
+use aoc_common::{solution, Solution};
+
+fn main() {
+    let parsed = solution::load::<Day08>(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/myinput.txt"));
+    println!("Result: {}", Day08::part1(&parsed));
+    println!("Result (part 2): {}", Day08::part2(&parsed));
+}
@@ -1,14 +1,7 @@
-use std::{fmt::Debug, collections::HashSet};
-
-fn file(path: &str) -> Vec<String> {
-    std::fs::read_to_string(path)
-        .unwrap()
-        .split("\n")
-        .into_iter()
-        .map(|it| String::from(it))
-        .collect::<Vec<String>>()
-}
+use aoc_common::{parser, ParseError, Result, Solution, Span};
+use std::{collections::HashSet, fmt::Debug};
 
+#[derive(Default)]
 struct Playground {
     min_x: i32,
     max_x: i32,
@@ -18,29 +11,17 @@ struct Playground {
     head: (i32, i32),
     tail: Vec<(i32, i32)>,
 
-    tail_history: HashSet<(i32, i32)>
-}
-
-impl Default for Playground {
-    fn default() -> Self {
-        Self { 
-            min_x: Default::default(),
-            max_x: Default::default(),
-            min_y: Default::default(),
-            max_y: Default::default(),
-            head: Default::default(), 
-            tail: Default::default(), 
-            tail_history: Default::default() 
-        }
-    }
+    tail_history: HashSet<(i32, i32)>,
 }
 
 impl Playground {
     fn new(tracking_level: usize) -> Playground {
-        if tracking_level == 0 {panic!("Must track at least 1 tail")}
+        if tracking_level == 0 {
+            panic!("Must track at least 1 tail")
+        }
 
         let mut instance = Playground {
-            tail: (0..tracking_level).map(|_| (0,0)).collect::<Vec<_>>(),
+            tail: (0..tracking_level).map(|_| (0, 0)).collect::<Vec<_>>(),
             ..Default::default()
         };
         instance.record_tail_position();
@@ -58,22 +39,26 @@ impl Playground {
         for i in 0..self.tail.len() {
             self.update_tail_n(i)
         }
-        
-        println!("progress {:?} {:?}", self.head, self.tail);
     }
 
     fn update_tail_n(&mut self, index: usize) {
-        if index >= self.tail.len() {panic!("Impossible to update unknown tail")}
+        if index >= self.tail.len() {
+            panic!("Impossible to update unknown tail")
+        }
 
-        let cl = self.tail.clone();
+        let _cl = self.tail.clone();
 
-        let head = if index == 0 {self.head} else {self.tail[index-1]};
-        let mut tail = self.tail.get_mut(index).unwrap();
+        let head = if index == 0 {
+            self.head
+        } else {
+            self.tail[index - 1]
+        };
+        let tail = self.tail.get_mut(index).unwrap();
 
         let diff_x = head.0.abs_diff(tail.0);
         let diff_y = head.1.abs_diff(tail.1);
 
-        let diag = if diff_x != 0 && diff_y != 0 {true} else {false};
+        let diag = diff_x != 0 && diff_y != 0;
 
         if diff_x == 2 && diff_y == 2 {
             tail.0 = (tail.0 + head.0) / 2;
@@ -91,7 +76,10 @@ impl Playground {
                 tail.0 = head.0;
             }
         } else if diff_x > 2 || diff_y > 2 {
-            panic!("Not supposed to happen {:?} {:?} {} {} {}", self.head, self.tail, index, diff_x, diff_y);
+            panic!(
+                "Not supposed to happen {:?} {:?} {} {} {}",
+                self.head, self.tail, index, diff_x, diff_y
+            );
         }
         // else do nothing
     }
@@ -105,7 +93,7 @@ impl Playground {
         for _a in 0..instruction.get_count() {
             self.head.0 += instruction.get_unit().0;
             self.head.1 += instruction.get_unit().1;
-            
+
             self.update_min_max();
             self.update_tail();
             self.record_tail_position();
@@ -119,19 +107,29 @@ impl Playground {
 
 impl Debug for Playground {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Playground [");
+        write!(f, "Playground [")?;
         let cols = self.max_x - self.min_x + 1;
         let rows = self.max_y - self.min_y + 1;
 
-        for (idx, letter) in (0..rows * cols).rev()
+        for (idx, letter) in (0..rows * cols)
+            .rev()
             // Number to row/cols
-            .map(|it| (it/cols + self.min_y, cols - 1 - it%cols + self.min_x))
+            .map(|it| (it / cols + self.min_y, cols - 1 - it % cols + self.min_x))
             // Draw that spot
             .map(|(row, col)| {
                 if self.head == (col, row) {
                     "H".into()
                 } else if self.tail.contains(&(col, row)) {
-                    format!("{}", self.tail.iter().enumerate().filter(|(_,el)| **el == (col, row)).next().unwrap().0+1)
+                    format!(
+                        "{}",
+                        self.tail
+                            .iter()
+                            .enumerate()
+                            .find(|(_, el)| **el == (col, row))
+                            .unwrap()
+                            .0
+                            + 1
+                    )
                 } else if row == 0 && col == 0 {
                     "s".into()
                 } else if self.tail_history.contains(&(col, row)) {
@@ -140,30 +138,30 @@ impl Debug for Playground {
                     ".".into()
                 }
             })
-            .enumerate() {
-            
-            if idx as i32%cols == 0 {
+            .enumerate()
+        {
+            if idx as i32 % cols == 0 {
                 if f.alternate() {
-                    write!(f, "\n  ");
+                    write!(f, "\n  ")?;
                 } else if idx != 0 {
-                    write!(f, " | ");
+                    write!(f, " | ")?;
                 }
             }
-            write!(f, "{}", letter);
+            write!(f, "{}", letter)?;
         }
         if f.alternate() {
-            write!(f, "\n");
+            writeln!(f)?;
         }
         writeln!(f, "]")
     }
 }
 
 #[derive(Debug, PartialEq)]
-enum Instruction {
+pub enum Instruction {
     Up(usize),
     Down(usize),
     Left(usize),
-    Right(usize)
+    Right(usize),
 }
 
 impl Instruction {
@@ -172,7 +170,7 @@ impl Instruction {
             Self::Up(_) => (0, 1),
             Self::Down(_) => (0, -1),
             Self::Left(_) => (-1, 0),
-            Self::Right(_) => (1, 0)
+            Self::Right(_) => (1, 0),
         }
     }
 
@@ -181,47 +179,66 @@ impl Instruction {
             Self::Up(ct) => *ct,
             Self::Down(ct) => *ct,
             Self::Left(ct) => *ct,
-            Self::Right(ct) => *ct
+            Self::Right(ct) => *ct,
         }
     }
 }
 
-impl From<&String> for Instruction {
-    fn from(str: &String) -> Self {
-        let elts = str.split_whitespace().collect::<Vec<_>>();
-        let nb = elts[1].parse::<usize>().unwrap();
-
-        match elts[0] {
-            "U" => Self::Up(nb),
-            "D" => Self::Down(nb),
-            "L" => Self::Left(nb),
-            "R" => Self::Right(nb),
-            _ => Self::Up(0)
-        }
+impl TryFrom<Span<'_>> for Instruction {
+    type Error = ParseError;
+
+    fn try_from(line: Span) -> Result<Self> {
+        let direction = line
+            .word(0, "`U`, `D`, `L` or `R`")?
+            .one_of(&["U", "D", "L", "R"])?;
+        let nb = line
+            .word(1, "a number of steps")?
+            .parse::<usize>("a number of steps")?;
+        line.end_after(2)?;
+
+        Ok(match direction {
+            0 => Self::Up(nb),
+            1 => Self::Down(nb),
+            2 => Self::Left(nb),
+            _ => Self::Right(nb),
+        })
     }
 }
 
-fn main() {
-    let lines = file("tests/myinput.txt");
-    let instructions = lines.iter().map(Instruction::from).collect::<Vec<_>>();
+fn count_tail_visited(instructions: &[Instruction], tracking_level: usize) -> usize {
+    let mut play = Playground::new(tracking_level);
+    instructions.iter().for_each(|it| play.r#move(it));
+    play.count_tail_visited()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Instruction>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parser::lines(input).map(Instruction::try_from).collect()
+    }
 
-    let mut play1 = Playground::new(1);
-    instructions.iter().for_each(|it| play1.r#move(it));
-    println!("Result: {}", play1.count_tail_visited());
+    fn part1(instructions: &Self::Input) -> usize {
+        count_tail_visited(instructions, 1)
+    }
 
-    let mut play2 = Playground::new(9);
-    instructions.iter().for_each(|it| play2.r#move(it));
-    println!("Result (part 2): {}", play2.count_tail_visited());
+    fn part2(instructions: &Self::Input) -> usize {
+        count_tail_visited(instructions, 9)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Playground, Instruction};
+    use crate::{Day09, Instruction, Playground};
+    use aoc_common::{input, ParseError, Solution};
 
     #[test]
     fn example() {
-        let lines = crate::file("tests/example.txt");
-        let instructions = lines.iter().map(Instruction::from).collect::<Vec<_>>();
+        let instructions = Day09::parse(&input::raw("tests/example.txt")).unwrap();
         let mut play = Playground::new(1);
 
         println!("{:#?}", play);
@@ -242,7 +259,8 @@ mod tests {
 
         println!("{:#?}", play);
 
-        assert_eq!(vec![
+        assert_eq!(
+            vec![
                 Instruction::Right(4),
                 Instruction::Up(4),
                 Instruction::Left(3),
@@ -252,23 +270,55 @@ mod tests {
                 Instruction::Left(5),
                 Instruction::Right(2)
             ],
-            instructions);
+            instructions
+        );
 
         let mut play2 = Playground::new(1);
         instructions.iter().for_each(|it| play2.r#move(it));
-            
+
         assert_eq!(13, play2.count_tail_visited());
     }
 
     #[test]
     fn example2() {
-        let lines = crate::file("tests/example2.txt");
-        let instructions = lines.iter().map(Instruction::from).collect::<Vec<_>>();
+        let instructions = Day09::parse(&input::raw("tests/example2.txt")).unwrap();
         let mut play = Playground::new(9);
         instructions.iter().for_each(|it| play.r#move(it));
 
         println!("{:#?}", play);
-            
+
         assert_eq!(36, play.count_tail_visited());
     }
+
+    #[test]
+    fn solution() {
+        let instructions = Day09::parse(&input::raw("tests/example.txt")).unwrap();
+        assert_eq!(13, Day09::part1(&instructions));
+        assert_eq!(1, Day09::part2(&instructions));
+
+        let instructions = Day09::parse(&input::raw("tests/example2.txt")).unwrap();
+        assert_eq!(36, Day09::part2(&instructions));
+    }
+
+    #[test]
+    fn line_endings() {
+        let expected = Day09::parse(&input::raw("tests/example.txt")).unwrap();
+        for path in ["tests/example_crlf.txt", "tests/example_newline.txt"] {
+            let parsed = Day09::parse(&input::raw(path)).unwrap();
+            assert_eq!(Day09::part1(&expected), Day09::part1(&parsed));
+            assert_eq!(Day09::part2(&expected), Day09::part2(&parsed));
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Some(ParseError::new(2, 1, "`U`, `D`, `L` or `R`").found("X")),
+            Day09::parse("R 4\nX 4").err()
+        );
+        assert_eq!(
+            Some(ParseError::new(1, 3, "a number of steps").found("-4")),
+            Day09::parse("R -4").err()
+        );
+    }
 }
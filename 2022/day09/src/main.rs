@@ -0,0 +1,8 @@
+use aoc2022_day09::Day09;
+use aoc_common::{solution, Solution};
+
+fn main() {
+    let parsed = solution::load::<Day09>(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/myinput.txt"));
+    println!("Result: {}", Day09::part1(&parsed));
+    println!("Result (part 2): {}", Day09::part2(&parsed));
+}
@@ -1,23 +1,10 @@
-use std::ops::Index;
-
-fn file(path: &str) -> Vec<Command> {
-    std::fs::read_to_string(path)
-        .unwrap()
-        .split("\n")
-        .into_iter()
-        .map(|it| String::from(it))
-        .map(Command::from)
-        .collect::<Vec<_>>()
-}
+use aoc_common::{parser, ParseError, Result, Solution, Span};
 
-struct Program(Vec<Command>);
+pub struct Program(Vec<Command>);
 
 impl Program {
-    fn time_at(&self, index: usize) -> usize {
-        self.0[0..index]
-            .iter()
-            .map(Command::get_duration)
-            .fold(0, |acc, it| acc + it)
+    pub fn time_at(&self, index: usize) -> usize {
+        self.0[0..index].iter().map(Command::get_duration).sum()
     }
 
     fn index_at(&self, time: usize) -> usize {
@@ -46,7 +33,7 @@ impl Program {
                 Command::Addx(el) => *el,
                 _ => 0,
             })
-            .fold(0, |acc, el| acc + el)
+            .sum::<i32>()
     }
 
     fn signal_strength(&self, index: usize) -> i32 {
@@ -58,7 +45,7 @@ impl Program {
         let mut reg_x = 1i32;
 
         for cmd in self.0.iter() {
-            println!("new command: {:?}", cmd);
+            // println!("new command: {:?}", cmd);
             for _ in 0..cmd.get_duration() {
                 let pixel = (line.len() % width) as i32;
                 line += if pixel >= reg_x - 1 && pixel <= reg_x + 1 {
@@ -66,12 +53,12 @@ impl Program {
                 } else {
                     " "
                 };
-                println!(
-                    "pixel: {} x: {} adding: {}",
-                    pixel,
-                    reg_x,
-                    line.chars().last().unwrap()
-                );
+                // println!(
+                //     "pixel: {} x: {} adding: {}",
+                //     pixel,
+                //     reg_x,
+                //     line.chars().last().unwrap()
+                // );
                 if line.len() == length {
                     return line;
                 }
@@ -80,7 +67,7 @@ impl Program {
                 Command::Addx(el) => *el,
                 _ => 0,
             };
-            println!("new x: {}", reg_x);
+            // println!("new x: {}", reg_x);
         }
 
         line
@@ -112,37 +99,28 @@ impl Command {
     }
 }
 
-impl<T> From<T> for Command
-where
-    T: Into<String>,
-{
-    fn from(input: T) -> Self {
-        let str = input.into();
-        let mut it = str.split_whitespace();
-        if let Some(cmd) = it.next() {
-            if cmd == "addx" {
-                if let Some(num_str) = it.next() {
-                    if let Ok(num) = num_str.parse::<i32>() {
-                        return Self::Addx(num);
-                    }
-                }
+impl TryFrom<Span<'_>> for Command {
+    type Error = ParseError;
+
+    fn try_from(line: Span) -> Result<Self> {
+        match line
+            .word(0, "`noop` or `addx`")?
+            .one_of(&["noop", "addx"])?
+        {
+            0 => {
+                line.end_after(1)?;
+                Ok(Self::Noop)
+            }
+            _ => {
+                let num = line.word(1, "a number")?.parse::<i32>("a number")?;
+                line.end_after(2)?;
+                Ok(Self::Addx(num))
             }
         }
-
-        Self::Noop
     }
 }
 
-fn main() {
-    let lines = file("tests/myinput.txt");
-    let prog = Program(lines);
-
-    println!("Result: {}", part1(&prog));
-
-    println!("Result (part 2) [\n{}\n]", prog.draw(240, 40));
-}
-
-fn part1(prog: &Program) -> i32 {
+fn signal_sum(prog: &Program) -> i32 {
     prog.signal_strength(20)
         + prog.signal_strength(60)
         + prog.signal_strength(100)
@@ -151,14 +129,38 @@ fn part1(prog: &Program) -> i32 {
         + prog.signal_strength(220)
 }
 
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Program;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Program(
+            parser::lines(input)
+                .map(Command::try_from)
+                .collect::<Result<Vec<_>>>()?,
+        ))
+    }
+
+    fn part1(prog: &Self::Input) -> i32 {
+        signal_sum(prog)
+    }
+
+    fn part2(prog: &Self::Input) -> String {
+        prog.draw(240, 40)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::Program;
+    use crate::Day10;
+    use aoc_common::{input, ParseError, Solution};
 
     #[test]
     fn example() {
-        let lines = crate::file("tests/example.txt");
-        let prog = Program(lines);
+        let prog = Day10::parse(&input::raw("tests/example.txt")).unwrap();
 
         println!("{:?}", prog.0);
 
@@ -180,7 +182,7 @@ mod tests {
 
         println!("{}", prog.draw(240, 40));
         assert_eq!(
-            vec![
+            [
                 "##..##..##..##..##..##..##..##..##..##..".to_string(),
                 "###...###...###...###...###...###...###.".to_string(),
                 "####....####....####....####....####....".to_string(),
@@ -188,8 +190,39 @@ mod tests {
                 "######......######......######......####".to_string(),
                 "#######.......#######.......#######.....".to_string()
             ]
-            .join("\n"),
+            .join("\n")
+            // Dark pixels are drawn as spaces for readability
+            .replace('.', " "),
             prog.draw(240, 40)
         )
     }
+
+    #[test]
+    fn solution() {
+        let prog = Day10::parse(&input::raw("tests/example.txt")).unwrap();
+        assert_eq!(13140, Day10::part1(&prog));
+        assert_eq!(prog.draw(240, 40), Day10::part2(&prog));
+    }
+
+    #[test]
+    fn line_endings() {
+        let expected = Day10::parse(&input::raw("tests/example.txt")).unwrap();
+        for path in ["tests/example_crlf.txt", "tests/example_newline.txt"] {
+            let parsed = Day10::parse(&input::raw(path)).unwrap();
+            assert_eq!(Day10::part1(&expected), Day10::part1(&parsed));
+            assert_eq!(Day10::part2(&expected), Day10::part2(&parsed));
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Some(ParseError::new(2, 1, "`noop` or `addx`").found("add")),
+            Day10::parse("noop\nadd 3").err()
+        );
+        assert_eq!(
+            Some(ParseError::new(1, 5, "a number")),
+            Day10::parse("addx").err()
+        );
+    }
 }
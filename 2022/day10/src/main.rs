@@ -0,0 +1,8 @@
+use aoc2022_day10::Day10;
+use aoc_common::{solution, Solution};
+
+fn main() {
+    let parsed = solution::load::<Day10>(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/myinput.txt"));
+    println!("Result: {}", Day10::part1(&parsed));
+    println!("Result (part 2) [\n{}\n]", Day10::part2(&parsed));
+}
@@ -0,0 +1,343 @@
+use aoc_common::{parser, progress, ParseError, Result, Solution, Span};
+use std::ops::{Index, IndexMut};
+
+#[derive(Debug)]
+pub struct Game(Vec<Monkey>);
+
+impl Game {
+    fn get_round_inspections(&self, index: usize, releaf: bool) -> Vec<usize> {
+        let mut round = self.0.iter().map(|it| it.items.clone()).collect::<Vec<_>>();
+        let mut inspections = self.0.iter().map(|_| 0).collect::<Vec<_>>();
+        let factor = self.0.iter().map(|it| it.test.divisible).product::<u128>();
+
+        let bar = progress::bar(index as u64);
+
+        for _round_idx in 0..index {
+            // println!("Round {:.1} %", round_idx as f64 / index as f64 * 100f64);
+
+            // println!("# Round {}", index);
+            for (idx_monkey, monkey) in self.0.iter().enumerate() {
+                // println!("Monkey {}:", idx_monkey);
+                for _idx_item in 0..round.index(idx_monkey).len() {
+                    *inspections.index_mut(idx_monkey) += 1;
+                    let item = round[idx_monkey].remove(0);
+                    // println!("  Monkey inspects an item with a worry level of {}.", item);
+                    let result = monkey.operation.exec(&item) % factor;
+                    // println!("    Worry level {} to {}.", monkey.operation, result);
+
+                    // part 2 no longer divides (uncomment for part 1)
+                    let bored = if releaf { result / 3 } else { result };
+
+                    // println!(
+                    //     "    Monkey gets bored with item. Worry level is divided by 3 to {}.",
+                    //     bored
+                    // );
+                    let dest_monkey = monkey.test.monkey_for(&bored);
+                    // println!(
+                    //     "    Item with worry level {} is thrown to monkey {}.",
+                    //     bored, dest_monkey
+                    // );
+
+                    round[dest_monkey].push(bored);
+                }
+            }
+
+            bar.inc(1);
+        }
+
+        bar.finish();
+
+        inspections
+    }
+}
+
+#[derive(Debug)]
+struct Monkey {
+    operation: Operation,
+    test: Test,
+
+    items: Vec<u128>,
+}
+
+/// Line `index` of a monkey description, without its indentation.
+fn field<'a>(block: &[Span<'a>], index: usize, prefix: &str) -> Result<Span<'a>> {
+    match block.get(index) {
+        Some(line) => line.trim().strip_prefix(prefix),
+        None => Err(block[block.len() - 1].end_error(format!("a line `{}`", prefix.trim()))),
+    }
+}
+
+impl TryFrom<&[Span<'_>]> for Monkey {
+    type Error = ParseError;
+
+    /**
+    Monkey 0:
+      Starting items: 79, 98
+      Operation: new = old * 19
+      Test: divisible by 23
+        If true: throw to monkey 2
+        If false: throw to monkey 3
+        */
+    fn try_from(block: &[Span]) -> Result<Self> {
+        let starting = field(block, 1, "Starting items:")?.trim();
+        let items = if starting.is_empty() {
+            vec![]
+        } else {
+            starting
+                .split(",")
+                .iter()
+                .map(|it| it.trim().parse::<u128>("a worry level"))
+                .collect::<Result<Vec<_>>>()?
+        };
+
+        let operation = Operation::try_from(field(block, 2, "Operation: new = old ")?)?;
+
+        let test = Test::try_from(&block[..block.len().min(6)])?;
+
+        if let Some(extra) = block.get(6) {
+            return Err(extra.error("a blank line"));
+        }
+
+        Ok(Self {
+            operation,
+            test,
+            items,
+        })
+    }
+}
+
+#[derive(Debug)]
+enum Operation {
+    Add(u128),
+    Multiply(u128),
+    Squared,
+}
+
+impl Operation {
+    fn exec(&self, num: &u128) -> u128 {
+        match self {
+            Self::Add(i) => num + (*i),
+            Self::Multiply(i) => num * (*i),
+            Self::Squared => num * num,
+        }
+    }
+}
+
+impl std::fmt::Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} by {}",
+            match self {
+                Self::Add(_) => "increases",
+                Self::Multiply(_) => "is multiplied",
+                Self::Squared => "is multiplied",
+            },
+            match self {
+                Self::Add(i) => i.to_string(),
+                Self::Multiply(i) => i.to_string(),
+                Self::Squared => "itself".to_string(),
+            }
+        )
+    }
+}
+
+impl TryFrom<Span<'_>> for Operation {
+    type Error = ParseError;
+
+    fn try_from(inp: Span) -> Result<Self> {
+        let op = inp.word(0, "`+` or `*`")?.one_of(&["+", "*"])?;
+        let operand = inp.word(1, "a number or `old`")?;
+        inp.end_after(2)?;
+
+        Ok(match (op, operand.text) {
+            (0, "old") => Self::Multiply(2),
+            (1, "old") => Self::Squared,
+            (0, _) => Self::Add(operand.parse::<u128>("a number or `old`")?),
+            _ => Self::Multiply(operand.parse::<u128>("a number or `old`")?),
+        })
+    }
+}
+
+/// Monkey an item is thrown to, along with where the input names it.
+#[derive(Debug)]
+struct Target {
+    monkey: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Target {
+    fn parse(number: &Span) -> Result<Self> {
+        Ok(Self {
+            monkey: number.parse::<usize>("a monkey number")?,
+            line: number.line,
+            column: number.column,
+        })
+    }
+
+    /// Checks that the monkey is one of the `count` ones.
+    fn check(&self, count: usize) -> Result<()> {
+        if self.monkey >= count {
+            return Err(ParseError::new(
+                self.line,
+                self.column,
+                format!("a monkey below {}", count),
+            )
+            .found(self.monkey.to_string()));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct Test {
+    divisible: u128,
+    monkey_ok: Target,
+    monkey_ko: Target,
+}
+
+impl TryFrom<&[Span<'_>]> for Test {
+    type Error = ParseError;
+
+    fn try_from(block: &[Span]) -> Result<Self> {
+        let divisor = field(block, 3, "Test: divisible by ")?;
+        let divisible = divisor.parse::<u128>("a divisor")?;
+        if divisible == 0 {
+            return Err(divisor.error("a divisor"));
+        }
+
+        let monkey_ok = Target::parse(&field(block, 4, "If true: throw to monkey ")?)?;
+        let monkey_ko = Target::parse(&field(block, 5, "If false: throw to monkey ")?)?;
+
+        Ok(Test {
+            divisible,
+            monkey_ok,
+            monkey_ko,
+        })
+    }
+}
+
+impl Test {
+    fn monkey_for(&self, num: &u128) -> usize {
+        if num.is_multiple_of(self.divisible) {
+            self.monkey_ok.monkey
+        } else {
+            self.monkey_ko.monkey
+        }
+    }
+}
+
+fn monkey_business(game: &Game, rounds: usize, releaf: bool) -> usize {
+    let mut inspections = game.get_round_inspections(rounds, releaf);
+    inspections.sort();
+    // println!("Inspections : {:?}", inspections);
+    // Parsing makes sure there are at least two monkeys
+    inspections.pop().unwrap() * inspections.pop().unwrap()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Game;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let blocks = parser::paragraphs(input);
+        let mut monkeys = vec![];
+        for (idx, block) in blocks.iter().enumerate() {
+            block[0].expect(&format!("Monkey {}:", idx))?;
+            monkeys.push(Monkey::try_from(block.as_slice())?);
+        }
+
+        // Monkey business needs two monkeys
+        if monkeys.len() < 2 {
+            return Err(match blocks.last().and_then(|it| it.last()) {
+                Some(line) => line.end_error("a second monkey"),
+                None => ParseError::new(1, 1, "a monkey"),
+            });
+        }
+
+        // Every monkey must throw to a monkey that exists
+        for monkey in monkeys.iter() {
+            monkey.test.monkey_ok.check(monkeys.len())?;
+            monkey.test.monkey_ko.check(monkeys.len())?;
+        }
+
+        Ok(Game(monkeys))
+    }
+
+    fn part1(game: &Self::Input) -> usize {
+        monkey_business(game, 20, true)
+    }
+
+    fn part2(game: &Self::Input) -> usize {
+        monkey_business(game, 10000, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{monkey_business, Day11};
+    use aoc_common::{input, ParseError, Solution};
+
+    #[test]
+    fn example() {
+        let game = Day11::parse(&input::raw("tests/example.txt")).unwrap();
+
+        println!("{:#?}", game.0);
+
+        assert_eq!(10605, monkey_business(&game, 20, true));
+        println!("{:?}", game.get_round_inspections(20, false));
+    }
+
+    #[test]
+    fn solution() {
+        let game = Day11::parse(&input::raw("tests/example.txt")).unwrap();
+        assert_eq!(10605, Day11::part1(&game));
+        assert_eq!(2713310158, Day11::part2(&game));
+    }
+
+    #[test]
+    fn line_endings() {
+        let expected = Day11::parse(&input::raw("tests/example.txt")).unwrap();
+        for path in ["tests/example_crlf.txt", "tests/example_newline.txt"] {
+            let parsed = Day11::parse(&input::raw(path)).unwrap();
+            assert_eq!(Day11::part1(&expected), Day11::part1(&parsed));
+            assert_eq!(Day11::part2(&expected), Day11::part2(&parsed));
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let other = "Monkey 1:\n  Starting items:\n  Operation: new = old + 1\n  Test: divisible by 2\n    If true: throw to monkey 0\n    If false: throw to monkey 0";
+        let monkey = |operation: &str, target: &str| {
+            format!(
+                "Monkey 0:\n  Starting items: 79, 98\n  Operation: new = old {}\n  Test: divisible by 23\n    If true: throw to monkey 0\n    If false: throw to monkey {}\n\n{}",
+                operation, target, other
+            )
+        };
+        assert!(Day11::parse(&monkey("* 19", "0")).is_ok());
+        assert_eq!(
+            Some(ParseError::new(3, 24, "`+` or `*`").found("/")),
+            Day11::parse(&monkey("/ 19", "0")).err()
+        );
+        assert_eq!(
+            Some(ParseError::new(6, 31, "a monkey below 2").found("3")),
+            Day11::parse(&monkey("* 19", "3")).err()
+        );
+        assert_eq!(
+            Some(ParseError::new(1, 10, "a line `Starting items:`")),
+            Day11::parse("Monkey 0:").err()
+        );
+        assert_eq!(
+            Some(ParseError::new(6, 32, "a second monkey")),
+            Day11::parse(&monkey("* 19", "0").replace(&format!("\n\n{}", other), "")).err()
+        );
+        assert_eq!(
+            Some(ParseError::new(1, 1, "a monkey")),
+            Day11::parse("").err()
+        );
+    }
+}
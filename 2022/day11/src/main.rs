@@ -0,0 +1,8 @@
+use aoc2022_day11::Day11;
+use aoc_common::{solution, Solution};
+
+fn main() {
+    let parsed = solution::load::<Day11>(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/myinput.txt"));
+    println!("Result: {}", Day11::part1(&parsed));
+    println!("Result (part 2): {}", Day11::part2(&parsed));
+}
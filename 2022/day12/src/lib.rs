@@ -1,22 +1,11 @@
+use aoc_common::{parser, progress, Solution, Span};
 use std::{
     collections::{HashMap, HashSet},
     fmt::Debug,
-    hash::Hash,
-    ops::{DivAssign, Index},
+    ops::Index,
 };
 
-use indicatif::{ProgressBar, ProgressStyle};
-
-fn file(path: &str) -> Vec<String> {
-    std::fs::read_to_string(path)
-        .unwrap()
-        .split("\n")
-        .into_iter()
-        .map(|it| String::from(it))
-        .collect::<Vec<String>>()
-}
-
-struct Grid {
+pub struct Grid {
     data: Vec<u8>,
 
     cols: usize,
@@ -39,10 +28,7 @@ impl Grid {
 
     fn valid_row(&self, len: usize) -> Result<(), String> {
         if self.cols != 0 && self.cols != len {
-            Err(format!(
-                "Row does not have valid length: expected {}, got {}",
-                self.rows, len
-            ))
+            Err(format!("{} squares", self.cols))
         } else {
             Ok(())
         }
@@ -59,40 +45,39 @@ impl Grid {
         }
     }
 
-    fn push_row(&mut self, added: &Vec<u8>) {
-        self.valid_row(added.len()).unwrap();
-
-        // Update begin if encountered
-        if added.contains(&0) {
-            self.begin = Some((
-                added
-                    .iter()
-                    .enumerate()
-                    .filter(|(_, it)| **it == 0)
-                    .next()
-                    .unwrap()
-                    .0,
-                self.rows,
-            ));
-        }
-
-        // Update end if encountered
-        if added.contains(&27) {
-            self.end = Some((
-                added
-                    .iter()
-                    .enumerate()
-                    .filter(|(_, it)| **it == 27)
-                    .next()
-                    .unwrap()
-                    .0,
-                self.rows,
-            ));
+    fn push_row(&mut self, row: &Span) -> aoc_common::Result<()> {
+        let squares = row.chars();
+        self.valid_row(squares.len())
+            .map_err(|expected| match squares.get(self.cols) {
+                Some(extra) => extra.error("end of line"),
+                None => row.end_error(expected),
+            })?;
+
+        let mut added = Vec::with_capacity(squares.len());
+        for (col, square) in squares.iter().enumerate() {
+            let height = match square.text {
+                "S" if self.begin.is_some() => Err(square.error("a single start `S`")),
+                "S" => {
+                    self.begin = Some((col, self.rows));
+                    Ok(0)
+                }
+                "E" if self.end.is_some() => Err(square.error("a single end `E`")),
+                "E" => {
+                    self.end = Some((col, self.rows));
+                    Ok(27)
+                }
+                _ => match square.text.as_bytes() {
+                    [chr @ b'a'..=b'z'] => Ok(chr - 96),
+                    _ => Err(square.error("a height (`a`-`z`), `S` or `E`")),
+                },
+            }?;
+            added.push(height);
         }
 
         self.data.extend(added);
-        self.cols = added.len();
+        self.cols = squares.len();
         self.rows += 1;
+        Ok(())
     }
 
     fn probe_index(&self, (x, y): (i32, i32)) -> Option<u8> {
@@ -111,7 +96,7 @@ impl Grid {
 
         let val = self[y as usize][x as usize];
 
-        vec![
+        [
             // Up
             (x, y - 1),
             // Down
@@ -133,10 +118,7 @@ impl Grid {
     }
 
     fn num_steps<T>(distances: &HashMap<T, u32>) -> u32 {
-        distances
-            .iter()
-            .map(|(_, i)| *i)
-            .fold(0, |acc, it| std::cmp::max(acc, it))
+        distances.values().copied().fold(0, std::cmp::max)
     }
 
     fn compute_steps(&self) -> u32 {
@@ -152,18 +134,13 @@ impl Grid {
 
         let mut old_len: usize = 1;
 
-        let bar = ProgressBar::new(self.cols as u64 * self.rows as u64);
+        let bar = progress::bar(self.cols as u64 * self.rows as u64);
         loop {
             // Calculate progress
             let step = Grid::num_steps(&distances);
 
             // Compute new possible steps
             let mut to_add = HashSet::<(usize, usize)>::new();
-            bar.set_style(
-                ProgressStyle::with_template("[{bar:40.cyan/blue}] {pos:>7}/{len:7} {msg}")
-                    .unwrap()
-                    .progress_chars("=> "),
-            );
 
             for (x, y) in distances
                 .iter()
@@ -213,18 +190,13 @@ impl Grid {
 
         let mut old_len: usize = 1;
 
-        let bar = ProgressBar::new(self.cols as u64 * self.rows as u64);
+        let bar = progress::bar(self.cols as u64 * self.rows as u64);
         loop {
             // Calculate progress
             let step = Grid::num_steps(&distances);
 
             // Compute new possible steps
             let mut to_add = HashSet::<(usize, usize)>::new();
-            bar.set_style(
-                ProgressStyle::with_template("[{bar:40.cyan/blue}] {pos:>7}/{len:7} {msg}")
-                    .unwrap()
-                    .progress_chars("=> "),
-            );
 
             for (x, y) in distances
                 .iter()
@@ -271,25 +243,23 @@ impl Index<usize> for Grid {
     }
 }
 
-impl From<&Vec<String>> for Grid {
-    fn from(str_vec: &Vec<String>) -> Self {
-        let mut grid = Grid::new();
-
-        for str in str_vec.iter() {
-            grid.push_row(
-                &str.chars()
-                    .into_iter()
-                    .map(|it| {
-                        (it as u8)
-                            .checked_sub(96)
-                            .unwrap_or(if it == 'S' { 0 } else { 27 })
-                    })
-                    .collect::<Vec<_>>(),
-            );
-        }
+fn parse_grid<'a, T: IntoIterator<Item = Span<'a>>>(lines: T) -> aoc_common::Result<Grid> {
+    let mut grid = Grid::new();
+    let mut last = Span::new(1, 1, "");
 
-        grid
+    for line in lines {
+        grid.push_row(&line)?;
+        last = line;
     }
+
+    if grid.begin.is_none() {
+        return Err(last.end_error("a start `S`"));
+    }
+    if grid.end.is_none() {
+        return Err(last.end_error("an end `E`"));
+    }
+
+    Ok(grid)
 }
 
 impl Debug for Grid {
@@ -324,22 +294,34 @@ impl Debug for Grid {
     }
 }
 
-fn main() {
-    let lines = file("tests/myinput.txt");
-    let mut grid = Grid::from(&lines);
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid;
+    type Answer1 = u32;
+    type Answer2 = u32;
 
-    println!("Result: {}", grid.compute_steps());
-    println!("Result (part 2): {}", grid.maximize_exercise());
+    fn parse(input: &str) -> aoc_common::Result<Self::Input> {
+        parse_grid(parser::lines(input))
+    }
+
+    fn part1(grid: &Self::Input) -> u32 {
+        grid.compute_steps()
+    }
+
+    fn part2(grid: &Self::Input) -> u32 {
+        grid.maximize_exercise()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::Grid;
+    use crate::Day12;
+    use aoc_common::{input, ParseError, Solution};
 
     #[test]
     fn valid_neighbours() {
-        let lines = crate::file("tests/example.txt");
-        let grid = Grid::from(&lines);
+        let grid = Day12::parse(&input::raw("tests/example.txt")).unwrap();
 
         let pred = |a, b| b <= a + 1;
 
@@ -475,12 +457,44 @@ mod tests {
 
     #[test]
     fn example() {
-        let lines = crate::file("tests/example.txt");
-        let mut grid = Grid::from(&lines);
+        let grid = Day12::parse(&input::raw("tests/example.txt")).unwrap();
 
         println!("{:#?}", grid);
 
         println!("{}", grid.compute_steps());
         println!("{}", grid.maximize_exercise());
     }
+
+    #[test]
+    fn solution() {
+        let grid = Day12::parse(&input::raw("tests/example.txt")).unwrap();
+        assert_eq!(31, Day12::part1(&grid));
+        assert_eq!(29, Day12::part2(&grid));
+    }
+
+    #[test]
+    fn line_endings() {
+        let expected = Day12::parse(&input::raw("tests/example.txt")).unwrap();
+        for path in ["tests/example_crlf.txt", "tests/example_newline.txt"] {
+            let parsed = Day12::parse(&input::raw(path)).unwrap();
+            assert_eq!(Day12::part1(&expected), Day12::part1(&parsed));
+            assert_eq!(Day12::part2(&expected), Day12::part2(&parsed));
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Some(ParseError::new(2, 2, "a height (`a`-`z`), `S` or `E`").found("1")),
+            Day12::parse("Sab\nz1E").err()
+        );
+        assert_eq!(
+            Some(ParseError::new(2, 3, "3 squares")),
+            Day12::parse("Sab\nzE").err()
+        );
+        assert_eq!(
+            Some(ParseError::new(2, 4, "an end `E`")),
+            Day12::parse("Sab\nzyx").err()
+        );
+    }
 }
@@ -0,0 +1,8 @@
+use aoc2022_day12::Day12;
+use aoc_common::{solution, Solution};
+
+fn main() {
+    let parsed = solution::load::<Day12>(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/myinput.txt"));
+    println!("Result: {}", Day12::part1(&parsed));
+    println!("Result (part 2): {}", Day12::part2(&parsed));
+}
@@ -0,0 +1,3 @@
+Day {{day}} of the Advent of Code {{year}}
+
+[adventofcode.com/{{year}}/day/{{day}}](https://adventofcode.com/{{year}}/day/{{day}})
@@ -0,0 +1,9 @@
+# Expected answers of every fixture in `tests/`, checked by `aoc check`
+
+["example.txt"]
+# part1 =
+# part2 =
+
+["myinput.txt"]
+# part1 =
+# part2 =
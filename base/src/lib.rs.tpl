@@ -0,0 +1,32 @@
+use aoc_common::{parser, Result, Solution};
+
+pub struct {{solution}};
+
+impl Solution for {{solution}} {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parser::lines(input).map(|it| it.text.to_string()).collect())
+    }
+
+    fn part1(lines: &Self::Input) -> usize {
+        lines.len()
+    }
+
+    fn part2(_lines: &Self::Input) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{{solution}};
+    use aoc_common::{input, Solution};
+
+    #[test]
+    fn example() {
+        assert!({{solution}}::parse(&input::raw("tests/example.txt")).is_ok());
+    }
+}
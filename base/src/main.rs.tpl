@@ -0,0 +1,8 @@
+use aoc_common::{solution, Solution};
+use {{lib}}::{{solution}};
+
+fn main() {
+    let parsed = solution::load::<{{solution}}>(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/myinput.txt"));
+    println!("Result: {}", {{solution}}::part1(&parsed));
+    println!("Result (part 2): {}", {{solution}}::part2(&parsed));
+}
@@ -0,0 +1,224 @@
+//! Expected answers of every fixture, so that refactors cannot silently change
+//! a result.
+//!
+//! Each day keeps an `answers.toml` next to its `tests` directory, with one
+//! table per fixture:
+//!
+//! ```toml
+//! ["example.txt"]
+//! part1 = 95437
+//! part2 = 24933642
+//! ```
+
+use std::{collections::BTreeMap, fmt, fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{error::Result, solution::Answers};
+
+/// Name of the manifest, relative to the day's directory.
+pub const MANIFEST: &str = "answers.toml";
+
+/// Answer as written in the manifest, either a number or some text.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(num) => write!(f, "{}", num),
+            Self::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+/// Answers of a single fixture, a missing part is not checked.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Expected {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Expected {
+    pub fn part(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Expected answers of a day, by fixture file name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Manifest(pub BTreeMap<String, Expected>);
+
+impl Manifest {
+    pub fn parse(input: &str) -> std::result::Result<Self, String> {
+        toml::from_str(input).map_err(|err| err.to_string())
+    }
+
+    pub fn load(path: &Path) -> std::result::Result<Self, String> {
+        let raw = fs::read_to_string(path)
+            .map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+        Self::parse(&raw).map_err(|err| format!("invalid {}: {}", path.display(), err))
+    }
+
+    pub fn get(&self, fixture: &str) -> Option<&Expected> {
+        self.0.get(fixture)
+    }
+}
+
+/// Every `*.txt` file of `dir`, sorted by name.
+pub fn fixtures(dir: &Path) -> std::result::Result<Vec<String>, String> {
+    let entries =
+        fs::read_dir(dir).map_err(|err| format!("cannot list {}: {}", dir.display(), err))?;
+
+    let mut names = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .filter(|name| name.ends_with(".txt"))
+        .collect::<Vec<_>>();
+    names.sort();
+
+    Ok(names)
+}
+
+/// Compares the answers of one fixture, returning a message per mismatch.
+pub fn compare(fixture: &str, expected: &Expected, answers: &dyn Answers) -> Vec<String> {
+    [1, 2]
+        .iter()
+        .filter_map(|&part| {
+            let wanted = expected.part(part)?.to_string();
+            let found = answers.part(part)?;
+            (wanted != found).then(|| {
+                format!(
+                    "{} part {}: expected {}, found {}",
+                    fixture, part, wanted, found
+                )
+            })
+        })
+        .collect()
+}
+
+/// Solves every fixture under `dir/tests` and checks it against
+/// `dir/answers.toml`.
+///
+/// Returns how many answers were verified, or every problem found: fixtures
+/// missing from the manifest, manifest entries without a fixture, invalid
+/// inputs and wrong answers.
+pub fn verify(
+    dir: &Path,
+    parse: fn(&str) -> Result<Box<dyn Answers>>,
+) -> std::result::Result<usize, Vec<String>> {
+    let manifest = Manifest::load(&dir.join(MANIFEST)).map_err(|err| vec![err])?;
+    let names = fixtures(&dir.join("tests")).map_err(|err| vec![err])?;
+
+    let mut problems = vec![];
+    let mut verified = 0;
+
+    for name in names.iter() {
+        let Some(expected) = manifest.get(name) else {
+            problems.push(format!("{}: no expected answers in {}", name, MANIFEST));
+            continue;
+        };
+
+        let path = dir.join("tests").join(name);
+        let raw = match fs::read_to_string(&path) {
+            Ok(raw) => raw,
+            Err(err) => {
+                problems.push(format!("{}: {}", name, err));
+                continue;
+            }
+        };
+
+        match parse(&raw) {
+            Ok(answers) => {
+                let mismatches = compare(name, expected, answers.as_ref());
+                verified += [1, 2]
+                    .iter()
+                    .filter(|&&it| expected.part(it).is_some())
+                    .count()
+                    - mismatches.len();
+                problems.extend(mismatches);
+            }
+            Err(err) => problems.push(err.with_file(name.as_str()).to_string()),
+        }
+    }
+
+    for name in manifest.0.keys().filter(|it| !names.contains(it)) {
+        problems.push(format!("{}: listed in {} but missing", name, MANIFEST));
+    }
+
+    if problems.is_empty() {
+        Ok(verified)
+    } else {
+        Err(problems)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::{compare, Answer, Expected, Manifest},
+        solution::{self, Solution},
+        Result,
+    };
+
+    struct Count;
+
+    impl Solution for Count {
+        type Input = usize;
+        type Answer1 = usize;
+        type Answer2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.len())
+        }
+
+        fn part1(input: &Self::Input) -> usize {
+            *input
+        }
+
+        fn part2(input: &Self::Input) -> String {
+            "#".repeat(*input)
+        }
+    }
+
+    #[test]
+    fn manifest() {
+        let manifest = Manifest::parse(
+            "[\"example.txt\"]\npart1 = 3\npart2 = '''\n###'''\n\n[\"myinput.txt\"]\npart2 = \"AB\"",
+        )
+        .unwrap();
+
+        assert_eq!(
+            Some(&Expected {
+                part1: Some(Answer::Number(3)),
+                part2: Some(Answer::Text("###".into())),
+            }),
+            manifest.get("example.txt")
+        );
+        assert_eq!(None, manifest.get("myinput.txt").unwrap().part(1));
+        assert!(Manifest::parse("part1 = [").is_err());
+    }
+
+    #[test]
+    fn mismatches() {
+        let answers = solution::parse::<Count>("abc").unwrap();
+        let expected = Expected {
+            part1: Some(Answer::Number(3)),
+            part2: Some(Answer::Text("##".into())),
+        };
+
+        assert_eq!(
+            vec!["example.txt part 2: expected ##, found ###".to_string()],
+            compare("example.txt", &expected, answers.as_ref())
+        );
+    }
+}
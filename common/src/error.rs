@@ -0,0 +1,141 @@
+//! Errors reported while reading puzzle inputs.
+
+use std::fmt;
+
+/// Location and reason of an invalid puzzle input.
+///
+/// Lines and columns both start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    /// What the parser was looking for, e.g. "a number"
+    pub expected: String,
+    /// Offending text, empty when the line ended too early
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new<T: Into<String>>(line: usize, column: usize, expected: T) -> Self {
+        Self {
+            file: None,
+            line,
+            column,
+            expected: expected.into(),
+            found: String::new(),
+        }
+    }
+
+    pub fn found<T: Into<String>>(mut self, found: T) -> Self {
+        self.found = found.into();
+        self
+    }
+
+    pub fn with_file<T: Into<String>>(mut self, file: T) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    fn location(&self) -> String {
+        match &self.file {
+            Some(file) => format!("{}:{}:{}", file, self.line, self.column),
+            None => format!("{}:{}", self.line, self.column),
+        }
+    }
+
+    fn reason(&self) -> String {
+        if self.found.is_empty() {
+            format!("expected {}, found end of line", self.expected)
+        } else {
+            format!("expected {}, found `{}`", self.expected, self.found)
+        }
+    }
+
+    /// Pretty diagnostic quoting the offending line of `source` and
+    /// underlining the unexpected text.
+    pub fn render(&self, source: &str) -> String {
+        let text = source
+            .split('\n')
+            .nth(self.line.saturating_sub(1))
+            .unwrap_or("")
+            .trim_start_matches('\u{feff}')
+            .trim_end_matches('\r');
+        let gutter = " ".repeat(self.line.to_string().len());
+        let underline = "^".repeat(std::cmp::max(1, self.found.chars().count()));
+
+        [
+            format!("error: {}", self.reason()),
+            format!("{}--> {}", gutter, self.location()),
+            format!("{} |", gutter),
+            format!("{} | {}", self.line, text),
+            format!(
+                "{} | {}{}",
+                gutter,
+                " ".repeat(self.column.saturating_sub(1)),
+                underline
+            ),
+        ]
+        .join("\n")
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.location(), self.reason())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ParseError;
+
+    #[test]
+    fn display() {
+        let err = ParseError::new(3, 5, "a number").found("x1");
+        assert_eq!("3:5: expected a number, found `x1`", err.to_string());
+
+        let err = ParseError::new(1, 4, "a stack number").with_file("input.txt");
+        assert_eq!(
+            "input.txt:1:4: expected a stack number, found end of line",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn render() {
+        let source = "1000\n2000\n30x0\n";
+        let err = ParseError::new(3, 3, "a number")
+            .found("x0")
+            .with_file("tests/example.txt");
+
+        assert_eq!(
+            [
+                "error: expected a number, found `x0`",
+                " --> tests/example.txt:3:3",
+                "  |",
+                "3 | 30x0",
+                "  |   ^^",
+            ]
+            .join("\n"),
+            err.render(source)
+        );
+
+        let err = ParseError::new(12, 7, "`to`");
+        assert_eq!(
+            [
+                "error: expected `to`, found end of line",
+                "  --> 12:7",
+                "   |",
+                "12 | ",
+                "   |       ^",
+            ]
+            .join("\n"),
+            err.render(source)
+        );
+    }
+}
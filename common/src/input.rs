@@ -0,0 +1,232 @@
+//! Puzzle input loading.
+//!
+//! Every loader comes in two flavours: one reading a file from disk, and a
+//! `split_*` one working on an already loaded string.
+//!
+//! The ones reading a file are meant for tests, with fixtures that must
+//! exist, and panic when the file cannot be read. Programs read their input
+//! through a [`Source`] instead, which reports I/O errors.
+
+use std::{
+    fs,
+    io::{self, BufRead, BufReader, Read},
+};
+
+use crate::parser;
+
+/// Reads the whole file as a single string, for tests.
+///
+/// Panics when the file cannot be read.
+pub fn raw(path: &str) -> String {
+    fs::read_to_string(path).unwrap_or_else(|err| panic!("Cannot read {}: {}", path, err))
+}
+
+/// Environment variable naming the puzzle input when no path is given.
+pub const INPUT_VAR: &str = "AOC_INPUT";
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(String),
+}
+
+impl Source {
+    /// `-` stands for the standard input, anything else is a path.
+    pub fn parse(arg: &str) -> Self {
+        match arg {
+            "-" => Self::Stdin,
+            path => Self::File(path.into()),
+        }
+    }
+
+    /// Input named by the command line `args` (either `PATH`, `-i PATH` or
+    /// `--input PATH`), else by `var`, else `default`.
+    pub fn resolve<I>(mut args: I, var: Option<String>, default: &str) -> Result<Self, String>
+    where
+        I: Iterator<Item = String>,
+    {
+        let arg = match args.next() {
+            Some(flag) if flag == "-i" || flag == "--input" => Some(
+                args.next()
+                    .ok_or(format!("missing path after `{}`", flag))?,
+            ),
+            Some(flag) if flag.starts_with("--") => {
+                return Err(format!("unknown option `{}`", flag));
+            }
+            other => other,
+        };
+        if let Some(extra) = args.next() {
+            return Err(format!("unexpected argument `{}`", extra));
+        }
+
+        Ok(Self::parse(
+            &arg.or(var.filter(|it| !it.is_empty()))
+                .unwrap_or_else(|| default.into()),
+        ))
+    }
+
+    /// Name used in diagnostics.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Stdin => "<stdin>",
+            Self::File(path) => path,
+        }
+    }
+
+    pub fn read(&self) -> Result<String, String> {
+        let read = match self {
+            Self::Stdin => {
+                let mut raw = String::new();
+                io::stdin().read_to_string(&mut raw).map(|_| raw)
+            }
+            Self::File(path) => fs::read_to_string(path),
+        };
+        read.map_err(|err| self.describe(err))
+    }
+
+    /// Reader over the input, for solutions consuming it line by line.
+    pub fn open(&self) -> Result<Box<dyn BufRead>, String> {
+        match self {
+            Self::Stdin => Ok(Box::new(io::stdin().lock())),
+            Self::File(path) => fs::File::open(path)
+                .map(|it| Box::new(BufReader::new(it)) as Box<dyn BufRead>)
+                .map_err(|err| self.describe(err)),
+        }
+    }
+
+    fn describe(&self, err: io::Error) -> String {
+        match (self, err.kind()) {
+            (Self::File(path), io::ErrorKind::NotFound) => format!(
+                "input {} does not exist\n  hint: pass the input path as argument, `-` to read it from stdin, or set {}",
+                path, INPUT_VAR
+            ),
+            _ => format!("cannot read input {}: {}", self.name(), err),
+        }
+    }
+}
+
+/// Reads the file and splits it into lines, for tests (see [`raw`]).
+pub fn lines(path: &str) -> Vec<String> {
+    split_lines(&raw(path))
+}
+
+/// Reads the file and groups lines separated by a blank line, for tests (see
+/// [`raw`]).
+pub fn paragraphs(path: &str) -> Vec<Vec<String>> {
+    split_paragraphs(&raw(path))
+}
+
+/// Reads the file and groups its lines by `size`, for tests (see [`raw`]).
+pub fn chunks(path: &str, size: usize) -> Vec<Vec<String>> {
+    split_chunks(&raw(path), size)
+}
+
+/// Lines of `input`, accepting `\r\n` endings and ignoring a byte order
+/// mark or trailing blank lines.
+pub fn split_lines(input: &str) -> Vec<String> {
+    parser::lines(input).map(|it| it.text.into()).collect()
+}
+
+pub fn split_paragraphs(input: &str) -> Vec<Vec<String>> {
+    let mut res = vec![];
+    let mut current = vec![];
+
+    for line in split_lines(input) {
+        if line.is_empty() {
+            res.push(current);
+            current = vec![];
+        } else {
+            current.push(line);
+        }
+    }
+    res.push(current);
+
+    res
+}
+
+pub fn split_chunks(input: &str, size: usize) -> Vec<Vec<String>> {
+    split_lines(input)
+        .chunks(size)
+        .map(|chunk| chunk.to_vec())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::{split_chunks, split_lines, split_paragraphs, Source};
+
+    #[test]
+    fn lines() {
+        assert_eq!(vec!["a", "b", "c"], split_lines("a\nb\nc"));
+        assert_eq!(vec![""], split_lines(""));
+        assert_eq!(vec!["a", "", "b"], split_lines("a\r\n\r\nb\r\n\r\n"));
+    }
+
+    #[test]
+    fn paragraphs() {
+        assert_eq!(
+            vec![vec!["1", "2"], vec!["3"], vec!["4", "5"]],
+            split_paragraphs("1\n2\n\n3\n\n4\n5")
+        );
+    }
+
+    #[test]
+    fn chunks() {
+        assert_eq!(
+            vec![vec!["a", "b", "c"], vec!["", "d", "e"], vec!["f"]],
+            split_chunks("a\nb\nc\n\nd\ne\nf", 3)
+        );
+    }
+
+    #[test]
+    fn source() {
+        let args = |list: &[&str]| list.iter().map(|it| it.to_string()).collect::<Vec<_>>();
+        let resolve = |list: &[&str], var: Option<&str>| {
+            Source::resolve(args(list).into_iter(), var.map(String::from), "default.txt")
+        };
+
+        assert_eq!(Ok(Source::File("default.txt".into())), resolve(&[], None));
+        assert_eq!(
+            Ok(Source::File("env.txt".into())),
+            resolve(&[], Some("env.txt"))
+        );
+        assert_eq!(
+            Ok(Source::File("default.txt".into())),
+            resolve(&[], Some(""))
+        );
+        assert_eq!(
+            Ok(Source::File("a.txt".into())),
+            resolve(&["a.txt"], Some("env.txt"))
+        );
+        assert_eq!(
+            Ok(Source::File("a.txt".into())),
+            resolve(&["--input", "a.txt"], None)
+        );
+        assert_eq!(Ok(Source::Stdin), resolve(&["-"], None));
+        assert_eq!(Ok(Source::Stdin), resolve(&["-i", "-"], None));
+
+        assert_eq!(
+            Err("missing path after `-i`".into()),
+            resolve(&["-i"], None)
+        );
+        assert_eq!(
+            Err("unknown option `--day`".into()),
+            resolve(&["--day"], None)
+        );
+        assert_eq!(
+            Err("unexpected argument `b.txt`".into()),
+            resolve(&["a.txt", "b.txt"], None)
+        );
+    }
+
+    #[test]
+    fn missing_file() {
+        let err = Source::parse("tests/missing.txt").read().unwrap_err();
+        assert!(err.starts_with("input tests/missing.txt does not exist\n"));
+        assert!(err.contains("AOC_INPUT"));
+
+        let err = Source::parse("tests/missing.txt").open().err().unwrap();
+        assert!(err.starts_with("input tests/missing.txt does not exist\n"));
+    }
+}
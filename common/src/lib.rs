@@ -0,0 +1,12 @@
+//! Helpers shared by every day of the Advent of Code.
+
+pub mod answers;
+pub mod error;
+pub mod input;
+pub mod parser;
+pub mod progress;
+pub mod solution;
+
+pub use error::{ParseError, Result};
+pub use parser::Span;
+pub use solution::Solution;
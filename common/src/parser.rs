@@ -0,0 +1,285 @@
+//! Building blocks for parsers reporting where the input went wrong.
+
+use std::str::FromStr;
+
+use crate::error::{ParseError, Result};
+
+/// Slice of the puzzle input remembering where it comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    pub line: usize,
+    pub column: usize,
+    pub text: &'a str,
+}
+
+/// Input without its byte order mark nor its trailing line breaks.
+fn trim_input(input: &str) -> &str {
+    input
+        .strip_prefix('\u{feff}')
+        .unwrap_or(input)
+        .trim_end_matches(['\r', '\n'])
+}
+
+/// Splits the input into lines, numbered from 1.
+///
+/// Both `\n` and `\r\n` line endings are accepted, and trailing blank lines
+/// are ignored.
+pub fn lines(input: &str) -> impl Iterator<Item = Span<'_>> {
+    trim_input(input)
+        .split('\n')
+        .enumerate()
+        .map(|(idx, text)| Span {
+            line: idx + 1,
+            column: 1,
+            text: text.strip_suffix('\r').unwrap_or(text),
+        })
+}
+
+/// Groups of lines separated by blank lines.
+pub fn paragraphs(input: &str) -> Vec<Vec<Span<'_>>> {
+    let mut res = vec![vec![]];
+    for line in lines(input) {
+        if line.is_empty() {
+            res.push(vec![]);
+        } else if let Some(last) = res.last_mut() {
+            last.push(line);
+        }
+    }
+    res.retain(|it| !it.is_empty());
+    res
+}
+
+/// Formats choices as "`A`, `B` or `C`".
+pub fn choices_list(choices: &[&str]) -> String {
+    let quoted = choices
+        .iter()
+        .map(|it| format!("`{}`", it))
+        .collect::<Vec<_>>();
+    match quoted.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+        None => "nothing".into(),
+    }
+}
+
+impl<'a> Span<'a> {
+    pub fn new(line: usize, column: usize, text: &'a str) -> Self {
+        Self { line, column, text }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Same span without its surrounding whitespace.
+    pub fn trim(&self) -> Span<'a> {
+        let start = self.text.len() - self.text.trim_start().len();
+        let end = self.text.trim_end().len().max(start);
+        self.slice(start, end)
+    }
+
+    /// Sub-span starting at byte `start` of this one.
+    pub fn slice(&self, start: usize, end: usize) -> Span<'a> {
+        Span {
+            line: self.line,
+            column: self.column + self.text[..start].chars().count(),
+            text: &self.text[start..end],
+        }
+    }
+
+    /// Error pointing at the whole span.
+    pub fn error<T: Into<String>>(&self, expected: T) -> ParseError {
+        ParseError::new(self.line, self.column, expected).found(self.text)
+    }
+
+    /// Error pointing right after the span, when something is missing.
+    pub fn end_error<T: Into<String>>(&self, expected: T) -> ParseError {
+        ParseError::new(self.line, self.column + self.text.chars().count(), expected)
+    }
+
+    pub fn parse<T: FromStr>(&self, expected: &str) -> Result<T> {
+        self.text.parse::<T>().map_err(|_| self.error(expected))
+    }
+
+    /// Index of the choice matching the span.
+    pub fn one_of(&self, choices: &[&str]) -> Result<usize> {
+        choices
+            .iter()
+            .position(|it| *it == self.text)
+            .ok_or_else(|| self.error(choices_list(choices)))
+    }
+
+    pub fn expect(&self, literal: &str) -> Result<()> {
+        self.one_of(&[literal]).map(|_| ())
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Span<'a>> {
+        if self.text.starts_with(prefix) {
+            Ok(self.slice(prefix.len(), self.text.len()))
+        } else {
+            let end = self
+                .text
+                .char_indices()
+                .nth(prefix.chars().count())
+                .map_or(self.text.len(), |(idx, _)| idx);
+            Err(self.slice(0, end).error(choices_list(&[prefix])))
+        }
+    }
+
+    /// Whitespace separated words.
+    pub fn words(&self) -> Vec<Span<'a>> {
+        let mut res = vec![];
+        let mut start = None;
+        for (idx, chr) in self.text.char_indices() {
+            match (start, chr.is_whitespace()) {
+                (None, false) => start = Some(idx),
+                (Some(begin), true) => {
+                    res.push(self.slice(begin, idx));
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(begin) = start {
+            res.push(self.slice(begin, self.text.len()));
+        }
+        res
+    }
+
+    /// `index`-th word, or an error at the end of the span when missing.
+    pub fn word(&self, index: usize, expected: &str) -> Result<Span<'a>> {
+        self.words()
+            .get(index)
+            .copied()
+            .ok_or_else(|| self.end_error(expected))
+    }
+
+    /// Fails when there are more than `count` words.
+    pub fn end_after(&self, count: usize) -> Result<()> {
+        match self.words().get(count) {
+            Some(extra) => Err(extra.error("end of line")),
+            None => Ok(()),
+        }
+    }
+
+    pub fn split(&self, separator: &str) -> Vec<Span<'a>> {
+        let mut res = vec![];
+        let mut start = 0;
+        for (idx, _) in self.text.match_indices(separator) {
+            res.push(self.slice(start, idx));
+            start = idx + separator.len();
+        }
+        res.push(self.slice(start, self.text.len()));
+        res
+    }
+
+    pub fn chars(&self) -> Vec<Span<'a>> {
+        self.text
+            .char_indices()
+            .map(|(idx, chr)| self.slice(idx, idx + chr.len_utf8()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::ParseError,
+        parser::{lines, paragraphs, Span},
+    };
+
+    #[test]
+    fn numbering() {
+        let spans = lines("a\n\nb c").collect::<Vec<_>>();
+        assert_eq!(Span::new(1, 1, "a"), spans[0]);
+        assert_eq!(Span::new(2, 1, ""), spans[1]);
+        assert_eq!(Span::new(3, 1, "b c"), spans[2]);
+    }
+
+    #[test]
+    fn line_endings() {
+        let spans = lines("\u{feff}a\r\n\r\nb\r\n\r\n").collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                Span::new(1, 1, "a"),
+                Span::new(2, 1, ""),
+                Span::new(3, 1, "b")
+            ],
+            spans
+        );
+        assert_eq!(vec![Span::new(1, 1, "")], lines("\n").collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn grouping() {
+        let groups = paragraphs("a\nb\n\n\nc\n");
+        assert_eq!(
+            vec![
+                vec![Span::new(1, 1, "a"), Span::new(2, 1, "b")],
+                vec![Span::new(5, 1, "c")]
+            ],
+            groups
+        );
+        assert_eq!(Span::new(3, 3, "x y"), Span::new(3, 1, "  x y ").trim());
+    }
+
+    #[test]
+    fn words() {
+        let line = Span::new(4, 1, "move 1  from 2");
+        assert_eq!(
+            vec![
+                Span::new(4, 1, "move"),
+                Span::new(4, 6, "1"),
+                Span::new(4, 9, "from"),
+                Span::new(4, 14, "2"),
+            ],
+            line.words()
+        );
+        assert_eq!(Err(ParseError::new(4, 15, "`to`")), line.word(4, "`to`"));
+        assert_eq!(Ok(()), line.end_after(4));
+        assert_eq!(
+            Err(ParseError::new(4, 9, "end of line").found("from")),
+            line.end_after(2)
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let span = Span::new(2, 3, "12x");
+        assert_eq!(
+            Err(ParseError::new(2, 3, "a number").found("12x")),
+            span.parse::<i32>("a number")
+        );
+        assert_eq!(Ok(12), span.slice(0, 2).parse::<i32>("a number"));
+        assert_eq!(Ok(1), Span::new(1, 1, "B").one_of(&["A", "B", "C"]));
+        assert_eq!(
+            Err(ParseError::new(1, 1, "`A`, `B` or `C`").found("D")),
+            Span::new(1, 1, "D").one_of(&["A", "B", "C"])
+        );
+    }
+
+    #[test]
+    fn split() {
+        let line = Span::new(1, 1, "2-4,6-8");
+        let ranges = line.split(",");
+        assert_eq!(vec![Span::new(1, 1, "2-4"), Span::new(1, 5, "6-8")], ranges);
+        assert_eq!(
+            vec![Span::new(1, 5, "6"), Span::new(1, 7, "8")],
+            ranges[1].split("-")
+        );
+        assert_eq!(Span::new(1, 3, "4"), line.chars()[2]);
+    }
+
+    #[test]
+    fn strip_prefix() {
+        let line = Span::new(2, 1, "  Starting items: 79, 98");
+        assert_eq!(
+            Ok(Span::new(2, 19, "79, 98")),
+            line.strip_prefix("  Starting items: ")
+        );
+        assert_eq!(
+            Err(ParseError::new(2, 1, "`  Operation: `").found("  Starting it")),
+            line.strip_prefix("  Operation: ")
+        );
+    }
+}
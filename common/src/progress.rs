@@ -0,0 +1,27 @@
+//! Progress bars of the slow days, which benchmarks hide.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
+
+static HIDDEN: AtomicBool = AtomicBool::new(false);
+
+/// Hides every progress bar created from now on, for timings not to include
+/// drawing them.
+pub fn hide() {
+    HIDDEN.store(true, Ordering::Relaxed);
+}
+
+/// Progress bar of `len` steps, drawn on the standard error unless hidden.
+pub fn bar(len: u64) -> ProgressBar {
+    let bar = ProgressBar::new(len);
+    if HIDDEN.load(Ordering::Relaxed) {
+        bar.set_draw_target(ProgressDrawTarget::hidden());
+    }
+    bar.set_style(
+        ProgressStyle::with_template("[{bar:40.cyan/blue}] {pos:>7}/{len:7} {msg}")
+            .unwrap()
+            .progress_chars("=> "),
+    );
+    bar
+}
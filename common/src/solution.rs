@@ -0,0 +1,199 @@
+//! Common shape of every day: parse the input once, then answer both parts.
+
+use std::fmt::Display;
+
+use crate::{
+    error::Result,
+    input::{Source, INPUT_VAR},
+};
+
+pub trait Solution {
+    /// Puzzle input once parsed, shared by both parts.
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Self::Answer1;
+
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// Parsed input of any [`Solution`], so that days can be stored side by side.
+pub trait Answers {
+    fn part1(&self) -> String;
+
+    fn part2(&self) -> String;
+
+    fn part(&self, part: u8) -> Option<String> {
+        match part {
+            1 => Some(self.part1()),
+            2 => Some(self.part2()),
+            _ => None,
+        }
+    }
+}
+
+struct Parsed<S: Solution>(S::Input);
+
+impl<S: Solution> Answers for Parsed<S> {
+    fn part1(&self) -> String {
+        S::part1(&self.0).to_string()
+    }
+
+    fn part2(&self) -> String {
+        S::part2(&self.0).to_string()
+    }
+}
+
+/// Parses `input` with `S`, hiding which day it belongs to.
+pub fn parse<S>(input: &str) -> Result<Box<dyn Answers>>
+where
+    S: Solution + 'static,
+{
+    Ok(Box::new(Parsed::<S>(S::parse(input)?)))
+}
+
+/// Reads and parses the puzzle input named on the command line (see
+/// [`Source::resolve`]), falling back to `default`.
+///
+/// Prints a diagnostic and exits when the input cannot be read or is invalid.
+pub fn load<S: Solution>(default: &str) -> S::Input {
+    load_from::<S, _>(std::env::args().skip(1), default)
+}
+
+/// Same as [`load`], for days taking options of their own out of the
+/// command line first.
+pub fn load_from<S, I>(args: I, default: &str) -> S::Input
+where
+    S: Solution,
+    I: Iterator<Item = String>,
+{
+    let (source, raw) = read_from(args, default);
+    or_exit(S::parse(&raw), &source, &raw)
+}
+
+/// Reads the puzzle input named by `args` (see [`Source::resolve`]), falling
+/// back to `default`, for days parsing it in more than one way.
+///
+/// Prints a diagnostic and exits when the input cannot be read.
+pub fn read_from<I>(args: I, default: &str) -> (Source, String)
+where
+    I: Iterator<Item = String>,
+{
+    let var = std::env::var(INPUT_VAR).ok();
+    let source = Source::resolve(args, var, default).unwrap_or_else(|err| fail(&err));
+    let raw = source.read().unwrap_or_else(|err| fail(&err));
+    (source, raw)
+}
+
+/// Value parsed from `raw`, read from `source`.
+///
+/// Prints a diagnostic quoting the input and exits when it is invalid.
+pub fn or_exit<T>(parsed: Result<T>, source: &Source, raw: &str) -> T {
+    match parsed {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("{}", err.with_file(source.name()).render(raw));
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Prints `message` as an error and exits.
+pub fn fail(message: &str) -> ! {
+    eprintln!("error: {}", message);
+    std::process::exit(1);
+}
+
+/// Removes every `short VALUE` or `long VALUE` option from `args`, returning
+/// the values in order.
+pub fn take_options(
+    args: &mut Vec<String>,
+    short: &str,
+    long: &str,
+) -> std::result::Result<Vec<String>, String> {
+    let mut res = vec![];
+    while let Some(idx) = args.iter().position(|it| it == short || it == long) {
+        let flag = args.remove(idx);
+        if idx == args.len() {
+            return Err(format!("missing value after `{}`", flag));
+        }
+        res.push(args.remove(idx));
+    }
+    Ok(res)
+}
+
+/// Removes every `short` or `long` switch from `args`, returning whether there
+/// was one.
+pub fn take_switch(args: &mut Vec<String>, short: &str, long: &str) -> bool {
+    let len = args.len();
+    args.retain(|it| it != short && it != long);
+    args.len() != len
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::{ParseError, Result},
+        parser::Span,
+        solution::{parse, take_options, take_switch, Solution},
+    };
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i32>;
+        type Answer1 = i32;
+        type Answer2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Span::new(1, 1, input)
+                .words()
+                .iter()
+                .map(|it| it.parse::<i32>("a number"))
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> i32 {
+            input.iter().sum()
+        }
+
+        fn part2(input: &Self::Input) -> String {
+            format!("{:?}", input)
+        }
+    }
+
+    #[test]
+    fn type_erased() {
+        let answers = parse::<Sum>("1 2 3").unwrap();
+        assert_eq!("6", answers.part1());
+        assert_eq!(Some("[1, 2, 3]".into()), answers.part(2));
+        assert_eq!(None, answers.part(3));
+
+        assert_eq!(
+            ParseError::new(1, 3, "a number").found("a"),
+            parse::<Sum>("1 a").err().unwrap()
+        );
+    }
+
+    #[test]
+    fn options() {
+        let args = |it: &str| it.split(' ').map(String::from).collect::<Vec<_>>();
+
+        let mut rest = args("-c 9000 input.txt --crane 9001 --lenient");
+        assert_eq!(
+            Ok(vec!["9000".to_string(), "9001".to_string()]),
+            take_options(&mut rest, "-c", "--crane")
+        );
+        assert!(take_switch(&mut rest, "-l", "--lenient"));
+        assert!(!take_switch(&mut rest, "-l", "--lenient"));
+        assert_eq!(args("input.txt"), rest);
+
+        assert_eq!(
+            Err("missing value after `-l`".into()),
+            take_options(&mut args("input.txt -l"), "-l", "--leaderboard")
+        );
+    }
+}
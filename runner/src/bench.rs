@@ -0,0 +1,256 @@
+//! Offline benchmarks of every stage of a day, compared with a saved baseline.
+//!
+//! Each stage runs repeatedly for a time budget and keeps its median time.
+//! Runs are saved as JSON under `target/aoc-bench/<name>.json`, so that a
+//! later run can report which stages got slower.
+
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    hint::black_box,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::days::Day;
+
+/// Directory of the saved baselines, relative to the workspace root.
+pub const BASELINES: &str = "target/aoc-bench";
+
+const MIN_ITERATIONS: usize = 5;
+const MAX_ITERATIONS: usize = 100_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub median_ns: u64,
+    pub min_ns: u64,
+    pub iterations: usize,
+}
+
+/// Runs `stage` at least a few times and until `budget` is spent.
+pub fn measure<T, F: FnMut() -> T>(mut stage: F, budget: Duration) -> Measurement {
+    // Warm up caches and allocator before timing
+    black_box(stage());
+
+    let mut times = vec![];
+    let start = Instant::now();
+    while times.len() < MIN_ITERATIONS || (start.elapsed() < budget && times.len() < MAX_ITERATIONS)
+    {
+        let begin = Instant::now();
+        black_box(stage());
+        times.push(begin.elapsed().as_nanos() as u64);
+    }
+    times.sort();
+
+    Measurement {
+        median_ns: times[times.len() / 2],
+        min_ns: times[0],
+        iterations: times.len(),
+    }
+}
+
+/// Measures parsing, part 1 and part 2 of `day` on `raw`.
+pub fn bench_day(
+    day: &Day,
+    raw: &str,
+    budget: Duration,
+) -> aoc_common::Result<Vec<(String, Measurement)>> {
+    // Drawing progress bars would be timed along with the solution
+    aoc_common::progress::hide();
+    let answers = (day.parse)(raw)?;
+
+    Ok(vec![
+        (
+            format!("{}/parse", day.dir()),
+            measure(|| (day.parse)(raw).is_ok(), budget),
+        ),
+        (
+            format!("{}/part1", day.dir()),
+            measure(|| answers.part1(), budget),
+        ),
+        (
+            format!("{}/part2", day.dir()),
+            measure(|| answers.part2(), budget),
+        ),
+    ])
+}
+
+/// Measurements of a run, by `<year>/dayNN/stage`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Baseline(pub BTreeMap<String, Measurement>);
+
+impl Baseline {
+    pub fn path(root: &Path, name: &str) -> PathBuf {
+        root.join(BASELINES).join(format!("{}.json", name))
+    }
+
+    /// Saved baseline called `name`, if any.
+    pub fn load(root: &Path, name: &str) -> Result<Option<Self>, String> {
+        let path = Self::path(root, name);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let raw = fs::read_to_string(&path)
+            .map_err(|err| format!("error: cannot read {}: {}", path.display(), err))?;
+        serde_json::from_str(&raw)
+            .map(Some)
+            .map_err(|err| format!("error: invalid baseline {}: {}", path.display(), err))
+    }
+
+    pub fn save(&self, root: &Path, name: &str) -> Result<(), String> {
+        let path = Self::path(root, name);
+        let write = || -> std::io::Result<()> {
+            fs::create_dir_all(root.join(BASELINES))?;
+            fs::write(&path, serde_json::to_string_pretty(self)?)
+        };
+        write().map_err(|err| format!("error: cannot save {}: {}", path.display(), err))
+    }
+}
+
+/// Current run next to a previous one.
+pub struct Comparison<'a> {
+    pub current: &'a Baseline,
+    pub previous: Option<&'a Baseline>,
+    /// Relative change above which a stage is reported, e.g. `0.1` for 10 %
+    pub threshold: f64,
+}
+
+impl Comparison<'_> {
+    /// Relative change of the median time of `stage`, when it was measured
+    /// before.
+    pub fn change(&self, stage: &str) -> Option<f64> {
+        let before = self.previous?.0.get(stage)?.median_ns as f64;
+        let now = self.current.0.get(stage)?.median_ns as f64;
+        Some((now - before) / before.max(1.))
+    }
+
+    /// Stages slower than the threshold.
+    pub fn regressions(&self) -> Vec<&str> {
+        self.current
+            .0
+            .keys()
+            .filter(|it| {
+                self.change(it)
+                    .is_some_and(|change| change > self.threshold)
+            })
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+impl fmt::Display for Comparison<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            " Stage            |     Median |   Baseline |   Change | Iterations"
+        )?;
+        write!(
+            f,
+            "------------------+------------+------------+----------+-----------"
+        )?;
+        for (stage, now) in self.current.0.iter() {
+            let baseline = self
+                .previous
+                .and_then(|it| it.0.get(stage))
+                .map_or("".into(), |it| {
+                    format!("{:.2?}", Duration::from_nanos(it.median_ns))
+                });
+            let change = self.change(stage);
+            let verdict = match change {
+                Some(it) if it > self.threshold => "  regressed",
+                Some(it) if it < -self.threshold => "  improved",
+                _ => "",
+            };
+            write!(
+                f,
+                "\n {:<16} | {:>10} | {:>10} | {:>8} | {:>10}{}",
+                stage,
+                format!("{:.2?}", Duration::from_nanos(now.median_ns)),
+                baseline,
+                change.map_or("".into(), |it| format!("{:+.1}%", it * 100.)),
+                now.iterations,
+                verdict
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, time::Duration};
+
+    use crate::bench::{measure, Baseline, Comparison, Measurement};
+
+    fn baseline(stages: &[(&str, u64)]) -> Baseline {
+        Baseline(
+            stages
+                .iter()
+                .map(|(stage, median_ns)| {
+                    (
+                        stage.to_string(),
+                        Measurement {
+                            median_ns: *median_ns,
+                            min_ns: *median_ns,
+                            iterations: 10,
+                        },
+                    )
+                })
+                .collect::<BTreeMap<_, _>>(),
+        )
+    }
+
+    #[test]
+    fn iterations() {
+        let mut calls = 0;
+        let result = measure(|| calls += 1, Duration::ZERO);
+        assert_eq!(5, result.iterations);
+        // One more call to warm up
+        assert_eq!(6, calls);
+        assert!(result.min_ns <= result.median_ns);
+    }
+
+    #[test]
+    fn comparison() {
+        let previous = baseline(&[("2022/day01/parse", 1000), ("2022/day01/part1", 2000)]);
+        let current = baseline(&[
+            ("2022/day01/parse", 1500),
+            ("2022/day01/part1", 1000),
+            ("2022/day02/parse", 500),
+        ]);
+        let comparison = Comparison {
+            current: &current,
+            previous: Some(&previous),
+            threshold: 0.1,
+        };
+
+        assert_eq!(Some(0.5), comparison.change("2022/day01/parse"));
+        assert_eq!(None, comparison.change("2022/day02/parse"));
+        assert_eq!(vec!["2022/day01/parse"], comparison.regressions());
+        assert_eq!(
+            [
+                " Stage            |     Median |   Baseline |   Change | Iterations",
+                "------------------+------------+------------+----------+-----------",
+                " 2022/day01/parse |     1.50µs |     1.00µs |   +50.0% |         10  regressed",
+                " 2022/day01/part1 |     1.00µs |     2.00µs |   -50.0% |         10  improved",
+                " 2022/day02/parse |   500.00ns |            |          |         10",
+            ]
+            .join("\n"),
+            comparison.to_string()
+        );
+    }
+
+    #[test]
+    fn json() {
+        let run = baseline(&[("2022/day01/parse", 1000)]);
+        let raw = serde_json::to_string(&run).unwrap();
+        assert_eq!(
+            r#"{"2022/day01/parse":{"median_ns":1000,"min_ns":1000,"iterations":10}}"#,
+            raw
+        );
+        assert_eq!(run, serde_json::from_str(&raw).unwrap());
+    }
+}
@@ -0,0 +1,58 @@
+//! Checks every fixture against the answers manifest of its day.
+
+use std::path::Path;
+
+use aoc_common::answers;
+
+use crate::days::Day;
+
+/// Verifies the fixtures of `days`, with paths relative to `root`.
+///
+/// The report has one line per day, followed by its problems if any, and is
+/// returned as an error as soon as one day fails.
+pub fn check(root: &Path, days: &[&Day]) -> Result<String, String> {
+    let mut report = vec![];
+    let mut failed = false;
+
+    for day in days {
+        match answers::verify(&root.join(day.dir()), day.parse) {
+            Ok(count) => report.push(format!(
+                "{} day {:>2}: {} answers verified",
+                day.year, day.day, count
+            )),
+            Err(problems) => {
+                failed = true;
+                report.push(format!(
+                    "{} day {:>2}: {} problems",
+                    day.year,
+                    day.day,
+                    problems.len()
+                ));
+                report.extend(problems.iter().map(|it| format!("  {}", it)));
+            }
+        }
+    }
+
+    if failed {
+        Err(report.join("\n"))
+    } else {
+        Ok(report.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{check::check, days::DAYS};
+
+    #[test]
+    fn golden() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let days = DAYS.iter().collect::<Vec<_>>();
+
+        if let Err(report) = check(&root, &days) {
+            panic!("{}", report);
+        }
+    }
+}
@@ -0,0 +1,250 @@
+//! HTTP client for an Advent of Code compatible server.
+
+use std::{
+    fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use ureq::{http::Response, Agent, Body};
+
+use crate::submit::Verdict;
+
+/// Environment variable holding the `session` cookie of the website.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable overriding the server, e.g. a local stand-in.
+pub const URL_VAR: &str = "AOC_URL";
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+
+/// Minimal delay between two requests, to be gentle with the server.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Spaces requests out, even across runs, by remembering when the last one
+/// was sent in a stamp file.
+pub struct Throttle {
+    pub stamp: PathBuf,
+    pub interval: Duration,
+}
+
+impl Throttle {
+    fn now() -> Duration {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+    }
+
+    /// Sleeps until `interval` has passed since the previous request, then
+    /// records this one.
+    pub fn wait(&self) -> Result<(), String> {
+        let last = fs::read_to_string(&self.stamp)
+            .ok()
+            .and_then(|it| it.trim().parse::<u64>().ok())
+            .map(Duration::from_millis);
+
+        if let Some(last) = last {
+            let elapsed = Self::now().saturating_sub(last);
+            if elapsed < self.interval {
+                thread::sleep(self.interval - elapsed);
+            }
+        }
+
+        if let Some(dir) = self.stamp.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|err| format!("cannot create {}: {}", dir.display(), err))?;
+        }
+        fs::write(&self.stamp, Self::now().as_millis().to_string())
+            .map_err(|err| format!("cannot write {}: {}", self.stamp.display(), err))
+    }
+}
+
+pub struct Client {
+    agent: Agent,
+    pub url: String,
+    session: String,
+    throttle: Throttle,
+}
+
+impl Client {
+    pub fn new(url: &str, session: &str, throttle: Throttle) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(concat!(
+                "github.com/charlesgael/aoc-2022 runner ",
+                env!("CARGO_PKG_VERSION")
+            ))
+            .build()
+            .into();
+
+        Self {
+            agent,
+            url: url.trim_end_matches('/').into(),
+            session: session.into(),
+            throttle,
+        }
+    }
+
+    /// Client configured by [`SESSION_VAR`] and [`URL_VAR`], when a session is
+    /// available.
+    pub fn from_env(throttle: Throttle) -> Option<Self> {
+        let session = std::env::var(SESSION_VAR)
+            .ok()
+            .filter(|it| !it.is_empty())?;
+        let url = std::env::var(URL_VAR).unwrap_or_else(|_| DEFAULT_URL.into());
+        Some(Self::new(&url, &session, throttle))
+    }
+
+    fn puzzle_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}", self.url, year, day)
+    }
+}
+
+/// Body of a successful response, or why the request failed.
+fn body(url: &str, result: Result<Response<Body>, ureq::Error>) -> Result<String, String> {
+    let mut response = result.map_err(|err| format!("request to {} failed: {}", url, err))?;
+    let status = response.status().as_u16();
+    let body = response
+        .body_mut()
+        .read_to_string()
+        .map_err(|err| format!("cannot read response of {}: {}", url, err))?;
+
+    match status {
+        200 => Ok(body),
+        400 | 401 | 403 => Err(format!(
+            "{} rejected the session token (status {}), check {}",
+            url, status, SESSION_VAR
+        )),
+        404 => Err(format!("{} does not exist yet (status 404)", url)),
+        _ => Err(format!("{} answered with status {}", url, status)),
+    }
+}
+
+impl Client {
+    /// Downloads the puzzle input of `day`.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, String> {
+        self.throttle.wait()?;
+
+        let url = format!("{}/input", self.puzzle_url(year, day));
+        let result = self
+            .agent
+            .get(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .call();
+        let body = body(&url, result)?;
+
+        if body.trim().is_empty() {
+            return Err(format!("{} returned an empty input", url));
+        }
+        Ok(body)
+    }
+
+    /// Posts `answer` for `part` of `day`, and reads the verdict of the server.
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Verdict, String> {
+        self.throttle.wait()?;
+
+        let url = format!("{}/answer", self.puzzle_url(year, day));
+        let result = self
+            .agent
+            .post(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .send_form([("level", part.to_string().as_str()), ("answer", answer)]);
+        verdict(&body(&url, result)?)
+    }
+}
+
+/// Verdict found in the page answering a submission.
+fn verdict(page: &str) -> Result<Verdict, String> {
+    if page.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if page.contains("That's not the right answer") {
+        Ok(if page.contains("too high") {
+            Verdict::TooHigh
+        } else if page.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        })
+    } else if page.contains("You gave an answer too recently") {
+        Err("the server asks to wait before answering again".into())
+    } else if page.contains("You don't seem to be solving the right level") {
+        Err("this part is already solved or still locked".into())
+    } else {
+        Err("cannot find the verdict in the response".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::{
+        client::{verdict, Client, Throttle},
+        stand_in::{self, temp_dir},
+        submit::Verdict,
+    };
+
+    fn throttle(name: &str, interval: Duration) -> Throttle {
+        Throttle {
+            stamp: temp_dir(name).join("last-request"),
+            interval,
+        }
+    }
+
+    #[test]
+    fn input() {
+        let server = stand_in::serve(vec![(200, "1000\n2000\n"), (404, "Not Found")]);
+        let client = Client::new(&server.url, "secret", throttle("input", Duration::ZERO));
+
+        assert_eq!(Ok("1000\n2000\n".into()), client.input(2022, 1));
+        assert_eq!(
+            Err(format!(
+                "{}/2022/day/25/input does not exist yet (status 404)",
+                server.url
+            )),
+            client.input(2022, 25)
+        );
+
+        let requests = server.finish();
+        assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0]
+            .to_lowercase()
+            .contains("cookie: session=secret\r\n"));
+    }
+
+    #[test]
+    fn rejected_session() {
+        let server = stand_in::serve(vec![(400, "Please log in")]);
+        let client = Client::new(&server.url, "expired", throttle("rejected", Duration::ZERO));
+
+        let err = client.input(2022, 1).unwrap_err();
+        assert!(err.contains("rejected the session token (status 400)"));
+    }
+
+    #[test]
+    fn rate_limit() {
+        let throttle = throttle("rate-limit", Duration::from_millis(200));
+
+        let start = Instant::now();
+        throttle.wait().unwrap();
+        assert!(start.elapsed() < Duration::from_millis(200));
+
+        // The stamp is shared, so a second run has to wait as well (stamps
+        // only keep milliseconds)
+        throttle.wait().unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(199));
+    }
+
+    #[test]
+    fn verdicts() {
+        assert_eq!(
+            Ok(Verdict::TooHigh),
+            verdict("<p>That's not the right answer; your answer is too high.</p>")
+        );
+        assert_eq!(
+            Ok(Verdict::Wrong),
+            verdict("<p>That's not the right answer. If you're stuck...</p>")
+        );
+        assert!(verdict("<p>You gave an answer too recently; wait 42s.</p>").is_err());
+    }
+}
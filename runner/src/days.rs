@@ -0,0 +1,123 @@
+//! Registry of every solved day, of every year.
+
+use aoc_common::{
+    solution::{self, Answers},
+    Result,
+};
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub parse: fn(&str) -> Result<Box<dyn Answers>>,
+}
+
+impl Day {
+    /// Crate of the day, relative to the workspace root.
+    pub fn dir(&self) -> String {
+        format!("{}/day{:02}", self.year, self.day)
+    }
+
+    /// Default location of the puzzle input, relative to the workspace root.
+    pub fn input_path(&self) -> String {
+        format!("{}/tests/myinput.txt", self.dir())
+    }
+}
+
+macro_rules! day {
+    ($year:expr, $day:expr, $solution:ty) => {
+        Day {
+            year: $year,
+            day: $day,
+            parse: solution::parse::<$solution>,
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(2022, 1, aoc2022_day01::Day01),
+    day!(2022, 2, aoc2022_day02::Day02),
+    day!(2022, 3, aoc2022_day03::Day03),
+    day!(2022, 4, aoc2022_day04::Day04),
+    day!(2022, 5, aoc2022_day05::Day05),
+    day!(2022, 6, aoc2022_day06::Day06),
+    day!(2022, 7, aoc2022_day07::Day07),
+    day!(2022, 8, aoc2022_day08::Day08),
+    day!(2022, 9, aoc2022_day09::Day09),
+    day!(2022, 10, aoc2022_day10::Day10),
+    day!(2022, 11, aoc2022_day11::Day11),
+    day!(2022, 12, aoc2022_day12::Day12),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|it| it.year == year && it.day == day)
+}
+
+/// Most recent year with a solved day, the default of every command.
+pub fn latest_year() -> u16 {
+    DAYS.iter().map(|it| it.year).max().unwrap_or(2022)
+}
+
+/// The requested day, or every day of the requested year, or every day when
+/// neither is given. A day without a year belongs to [`latest_year`].
+pub fn select(
+    year: Option<u16>,
+    day: Option<u8>,
+) -> std::result::Result<Vec<&'static Day>, String> {
+    match (year, day) {
+        (year, Some(num)) => {
+            let year = year.unwrap_or_else(latest_year);
+            Ok(vec![find(year, num).ok_or(format!(
+                "error: day {} of {} is not solved yet",
+                num, year
+            ))?])
+        }
+        (Some(year), None) => {
+            let days = DAYS.iter().filter(|it| it.year == year).collect::<Vec<_>>();
+            if days.is_empty() {
+                return Err(format!("error: no day of {} is solved yet", year));
+            }
+            Ok(days)
+        }
+        (None, None) => Ok(DAYS.iter().collect()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::{find, select, DAYS};
+
+    #[test]
+    fn registry() {
+        for pair in DAYS.windows(2) {
+            assert!((pair[0].year, pair[0].day) < (pair[1].year, pair[1].day));
+        }
+        assert_eq!(
+            "2022/day07/tests/myinput.txt",
+            find(2022, 7).unwrap().input_path()
+        );
+        assert!(find(2022, 26).is_none());
+        assert!(find(2015, 7).is_none());
+
+        let answers = (find(2022, 1).unwrap().parse)("1000\n2000\n\n4000").unwrap();
+        assert_eq!("4000", answers.part1());
+        assert_eq!("7000", answers.part2());
+    }
+
+    #[test]
+    fn selection() {
+        assert_eq!(DAYS.len(), select(None, None).unwrap().len());
+        assert_eq!(
+            DAYS.iter().filter(|it| it.year == 2022).count(),
+            select(Some(2022), None).unwrap().len()
+        );
+        assert_eq!("2022/day03", select(Some(2022), Some(3)).unwrap()[0].dir());
+        assert_eq!(
+            Err("error: day 3 of 2015 is not solved yet".into()),
+            select(Some(2015), Some(3)).map(|it| it.len())
+        );
+        assert_eq!(
+            Err("error: no day of 2015 is solved yet".into()),
+            select(Some(2015), None).map(|it| it.len())
+        );
+    }
+}
@@ -0,0 +1,152 @@
+//! Puzzle inputs cached on disk, downloaded at most once.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::client::{Client, Throttle, MIN_INTERVAL};
+
+/// Environment variable overriding where inputs are cached.
+pub const CACHE_VAR: &str = "AOC_CACHE_DIR";
+/// Default cache, relative to the workspace root.
+pub const DEFAULT_CACHE: &str = ".aoc/inputs";
+
+/// Directory of inputs, stored as `<year>/dayNN.txt`.
+pub struct Cache {
+    pub dir: PathBuf,
+}
+
+impl Cache {
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    /// Cached input, if it was already downloaded.
+    pub fn get(&self, year: u16, day: u8) -> Option<PathBuf> {
+        let path = self.path(year, day);
+        let size = fs::metadata(&path).ok()?.len();
+        (size > 0).then_some(path)
+    }
+
+    /// Saves an input, through a temporary file so that an interrupted write
+    /// never leaves a truncated input behind.
+    pub fn store(&self, year: u16, day: u8, content: &str) -> Result<PathBuf, String> {
+        let path = self.path(year, day);
+        let partial = path.with_extension("part");
+        let write = || -> std::io::Result<()> {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(&partial, content)?;
+            fs::rename(&partial, &path)
+        };
+        write().map_err(|err| format!("cannot save {}: {}", path.display(), err))?;
+        Ok(path)
+    }
+}
+
+pub struct Inputs {
+    pub cache: Cache,
+    /// Downloads missing inputs, when a session is configured
+    pub client: Option<Client>,
+}
+
+impl Inputs {
+    /// Cache from [`CACHE_VAR`] or `root/.aoc/inputs`, with a client when a
+    /// session token is set.
+    pub fn from_env(root: &Path) -> Self {
+        let dir = std::env::var(CACHE_VAR)
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| root.join(DEFAULT_CACHE));
+        let throttle = Throttle {
+            stamp: dir.join(".last-request"),
+            interval: MIN_INTERVAL,
+        };
+
+        Self {
+            client: Client::from_env(throttle),
+            cache: Cache { dir },
+        }
+    }
+
+    /// Path of the input of `day`, downloading it first when not cached.
+    pub fn resolve(&self, year: u16, day: u8) -> Result<PathBuf, String> {
+        if let Some(path) = self.cache.get(year, day) {
+            return Ok(path);
+        }
+
+        let client = self.client.as_ref().ok_or(format!(
+            "input of day {} ({}) is not cached in {}\n  hint: set {} to download it",
+            day,
+            year,
+            self.cache.dir.display(),
+            crate::client::SESSION_VAR
+        ))?;
+        let content = client.input(year, day)?;
+        self.cache.store(year, day, &content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, time::Duration};
+
+    use crate::{
+        client::{Client, Throttle},
+        inputs::{Cache, Inputs},
+        stand_in::{self, temp_dir},
+    };
+
+    fn inputs(name: &str, url: Option<&str>) -> Inputs {
+        let dir = temp_dir(name);
+        Inputs {
+            client: url.map(|url| {
+                let throttle = Throttle {
+                    stamp: dir.join(".last-request"),
+                    interval: Duration::ZERO,
+                };
+                Client::new(url, "secret", throttle)
+            }),
+            cache: Cache { dir },
+        }
+    }
+
+    #[test]
+    fn downloads_once() {
+        let server = stand_in::serve(vec![(200, "1000\n\n2000\n")]);
+        let inputs = inputs("downloads-once", Some(&server.url));
+
+        let path = inputs.resolve(2022, 1).unwrap();
+        assert!(path.ends_with("2022/day01.txt"));
+        assert_eq!("1000\n\n2000\n", fs::read_to_string(&path).unwrap());
+
+        // Served from the cache, the stand-in would refuse a second request
+        assert_eq!(path, inputs.resolve(2022, 1).unwrap());
+        assert_eq!(1, server.finish().len());
+    }
+
+    #[test]
+    fn offline() {
+        let inputs = inputs("offline", None);
+        inputs
+            .cache
+            .store(2022, 6, "mjqjpqmgbljsphdztnvjfqwrcgsmlb")
+            .unwrap();
+
+        assert!(inputs.resolve(2022, 6).is_ok());
+        let err = inputs.resolve(2022, 7).unwrap_err();
+        assert!(err.starts_with("input of day 7 (2022) is not cached"));
+    }
+
+    #[test]
+    fn failed_download() {
+        let server = stand_in::serve(vec![(500, "oops")]);
+        let inputs = inputs("failed-download", Some(&server.url));
+
+        assert!(inputs.resolve(2022, 2).is_err());
+        assert!(inputs.cache.get(2022, 2).is_none());
+    }
+}
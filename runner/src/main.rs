@@ -0,0 +1,317 @@
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc_common::input::Source;
+use clap::{Parser, Subcommand};
+
+use crate::{
+    bench::{Baseline, Comparison},
+    days::Day,
+    inputs::Inputs,
+    report::{Row, Table},
+    submit::{History, Verdict},
+};
+
+mod bench;
+mod check;
+mod client;
+mod days;
+mod inputs;
+mod report;
+mod scaffold;
+#[cfg(test)]
+mod stand_in;
+mod submit;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs one or every day and prints the answers
+    Run {
+        /// Year of the days, the most recent one when omitted with a day
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        /// Day to run, every day when omitted
+        #[arg(short, long)]
+        day: Option<u8>,
+
+        /// Part to run, both when omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Puzzle input, `-` for stdin, defaults to
+        /// `<year>/dayNN/tests/myinput.txt`
+        /// or the cached input
+        #[arg(short, long, requires = "day")]
+        input: Option<String>,
+    },
+    /// Measures parse and solve times, and compares them with a saved run
+    Bench {
+        /// Year of the days, the most recent one when omitted with a day
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        /// Day to measure, every day when omitted
+        #[arg(short, long)]
+        day: Option<u8>,
+
+        /// Time spent repeating each stage, in milliseconds
+        #[arg(long, default_value_t = 500)]
+        budget: u64,
+
+        /// Saved run to compare with, skipped when it does not exist yet
+        #[arg(long, default_value = "base")]
+        baseline: String,
+
+        /// Name under which this run is saved
+        #[arg(long, default_value = "base")]
+        save_baseline: String,
+
+        /// Slowdown reported as a regression, in percent
+        #[arg(long, default_value_t = 10.)]
+        threshold: f64,
+    },
+    /// Downloads a puzzle input into the cache, unless it is already there
+    Fetch {
+        #[arg(short, long)]
+        day: u8,
+
+        /// Defaults to the most recent year with a solved day
+        #[arg(short, long)]
+        year: Option<u16>,
+    },
+    /// Submits an answer, unless the local history proves it wrong
+    Submit {
+        #[arg(short, long)]
+        day: u8,
+
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Defaults to the most recent year with a solved day
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        /// Answer to send, computed from the puzzle input when omitted
+        answer: Option<String>,
+    },
+    /// Creates the crate of a new day from the templates of `base/`
+    New {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Defaults to the most recent year with a solved day
+        #[arg(short, long)]
+        year: Option<u16>,
+    },
+    /// Checks every fixture against the expected answers in `answers.toml`
+    Check {
+        /// Year of the days, the most recent one when omitted with a day
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        /// Day to check, every day when omitted
+        #[arg(short, long)]
+        day: Option<u8>,
+    },
+}
+
+/// Committed puzzle input of `day`, else the cached one, downloading it when
+/// needed.
+fn default_input(day: &Day) -> Result<String, String> {
+    let path = day.input_path();
+    if std::fs::metadata(&path).is_ok_and(|it| it.len() > 0) {
+        return Ok(path);
+    }
+    Inputs::from_env(Path::new("."))
+        .resolve(day.year, day.day)
+        .map(|it| it.display().to_string())
+}
+
+/// Runs the selected days, failing with a diagnostic ready to be printed.
+fn run(
+    (year, day): (Option<u16>, Option<u8>),
+    part: Option<u8>,
+    input: Option<String>,
+) -> Result<Table, String> {
+    let mut table = Table::default();
+    for day in days::select(year, day)? {
+        let source = match input.as_deref() {
+            Some(arg) => Source::parse(arg),
+            None => Source::File(default_input(day).map_err(|err| format!("error: {}", err))?),
+        };
+        let raw = source.read().map_err(|err| format!("error: {}", err))?;
+
+        let start = Instant::now();
+        let answers = (day.parse)(&raw).map_err(|err| err.with_file(source.name()).render(&raw))?;
+        let mut parse = Some(start.elapsed());
+
+        for num in part.map_or(vec![1, 2], |it| vec![it]) {
+            let start = Instant::now();
+            let answer = answers.part(num).unwrap();
+            table.push(Row {
+                year: day.year,
+                day: day.day,
+                part: num,
+                answer,
+                parse: parse.take(),
+                time: start.elapsed(),
+            });
+        }
+    }
+
+    Ok(table)
+}
+
+/// Benchmarks the selected days on their puzzle input, failing when a stage
+/// got slower than the baseline.
+fn bench(
+    (year, day): (Option<u16>, Option<u8>),
+    budget: Duration,
+    baseline: &str,
+    save_baseline: &str,
+    threshold: f64,
+) -> Result<String, String> {
+    let root = Path::new(".");
+    let previous = Baseline::load(root, baseline)?;
+
+    let mut current = Baseline::default();
+    for day in days::select(year, day)? {
+        let path = day.input_path();
+        let raw = std::fs::read_to_string(&path)
+            .map_err(|err| format!("error: cannot read input {}: {}", path, err))?;
+        let stages =
+            bench::bench_day(day, &raw, budget).map_err(|err| err.with_file(&path).render(&raw))?;
+        current.0.extend(stages);
+    }
+    // Keep the days that were not measured this time
+    let mut saved = Baseline::load(root, save_baseline)?.unwrap_or_default();
+    saved.0.extend(current.0.clone());
+    saved.save(root, save_baseline)?;
+
+    let comparison = Comparison {
+        current: &current,
+        previous: previous.as_ref(),
+        threshold: threshold / 100.,
+    };
+    let regressions = comparison.regressions();
+    if regressions.is_empty() {
+        Ok(comparison.to_string())
+    } else {
+        Err(format!(
+            "{}\nerror: {} stage(s) slower than `{}`: {}",
+            comparison,
+            regressions.len(),
+            baseline,
+            regressions.join(", ")
+        ))
+    }
+}
+
+/// Submits `answer`, or the one computed for `day`, recording the verdict in
+/// the history.
+fn submit_answer(year: u16, day: u8, part: u8, answer: Option<String>) -> Result<String, String> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let solved = days::find(year, day)
+                .ok_or(format!("day {} of {} is not solved yet", day, year))?;
+            let source = Source::File(default_input(solved)?);
+            let raw = source.read()?;
+            let answers =
+                (solved.parse)(&raw).map_err(|err| err.with_file(source.name()).to_string())?;
+            answers.part(part).unwrap_or_default()
+        }
+    };
+
+    let root = Path::new(".");
+    let path = std::env::var(submit::HISTORY_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| root.join(submit::DEFAULT_HISTORY));
+    let mut history = History::load(&path)?;
+    let client = Inputs::from_env(root)
+        .client
+        .ok_or(format!("set {} to submit answers", client::SESSION_VAR))?;
+
+    let verdict = submit::submit(&mut history, &client, year, (day, part), &answer);
+    history.save(&path)?;
+
+    match verdict? {
+        Verdict::Correct => Ok(format!("{} is the right answer", answer)),
+        wrong => Err(format!("{} is {}", answer, wrong)),
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+        } => run((year, day), part, input).map(|it| it.to_string()),
+        Command::Bench {
+            year,
+            day,
+            budget,
+            baseline,
+            save_baseline,
+            threshold,
+        } => bench(
+            (year, day),
+            Duration::from_millis(budget),
+            &baseline,
+            &save_baseline,
+            threshold,
+        ),
+        Command::Fetch { day, year } => Inputs::from_env(Path::new("."))
+            .resolve(year.unwrap_or_else(days::latest_year), day)
+            .map(|path| path.display().to_string())
+            .map_err(|err| format!("error: {}", err)),
+        Command::Submit {
+            day,
+            part,
+            year,
+            answer,
+        } => submit_answer(year.unwrap_or_else(days::latest_year), day, part, answer)
+            .map_err(|err| format!("error: {}", err)),
+        Command::New { day, year } => {
+            let year = year.unwrap_or_else(days::latest_year);
+            scaffold::new_day(Path::new("."), &scaffold::Context { day, year })
+                .map(|files| {
+                    files
+                        .iter()
+                        .map(|it| format!("updated {}", it.display()))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                })
+                .map_err(|err| format!("error: {}", err))
+        }
+        Command::Check { year, day } => {
+            days::select(year, day).and_then(|selected| check::check(Path::new("."), &selected))
+        }
+    };
+
+    match result {
+        Ok(report) => {
+            println!("{}", report);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
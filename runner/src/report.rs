@@ -0,0 +1,108 @@
+//! Table of answers printed by the runner.
+
+use std::{fmt, time::Duration};
+
+pub struct Row {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    /// Time spent parsing the input, only set on the first part ran of a day
+    pub parse: Option<Duration>,
+    pub time: Duration,
+}
+
+#[derive(Default)]
+pub struct Table(pub Vec<Row>);
+
+impl Table {
+    pub fn push(&mut self, row: Row) {
+        self.0.push(row);
+    }
+
+    pub fn total_time(&self) -> Duration {
+        self.0
+            .iter()
+            .map(|it| it.time + it.parse.unwrap_or_default())
+            .sum()
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .0
+            .iter()
+            .flat_map(|it| it.answer.lines())
+            .map(|it| it.chars().count())
+            .fold("Answer".len(), std::cmp::max);
+
+        writeln!(
+            f,
+            " Year | Day | Part | {:<width$} |      Parse |      Solve",
+            "Answer"
+        )?;
+        writeln!(
+            f,
+            "------+-----+------+-{}-+------------+-----------",
+            "-".repeat(width)
+        )?;
+        for row in self.0.iter() {
+            let mut lines = row.answer.lines();
+            let first = lines.next().unwrap_or("");
+            let parse = row.parse.map_or("".into(), |it| format!("{:.2?}", it));
+            let time = format!("{:.2?}", row.time);
+            writeln!(
+                f,
+                " {:>4} | {:>3} | {:>4} | {:<width$} | {:>10} | {:>10}",
+                row.year, row.day, row.part, first, parse, time
+            )?;
+            // Multi-line answers (ascii art) continue on their own rows
+            for line in lines {
+                writeln!(f, "      |     |      | {:<width$} |            |", line)?;
+            }
+        }
+        write!(f, "Total time: {:.2?}", self.total_time())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::report::{Row, Table};
+
+    #[test]
+    fn display() {
+        let mut table = Table::default();
+        table.push(Row {
+            year: 2022,
+            day: 1,
+            part: 1,
+            answer: "24000".into(),
+            parse: Some(Duration::from_millis(1)),
+            time: Duration::from_millis(2),
+        });
+        table.push(Row {
+            year: 2022,
+            day: 10,
+            part: 2,
+            answer: "#..#\n.##.".into(),
+            parse: None,
+            time: Duration::from_micros(500),
+        });
+
+        assert_eq!(
+            [
+                " Year | Day | Part | Answer |      Parse |      Solve",
+                "------+-----+------+--------+------------+-----------",
+                " 2022 |   1 |    1 | 24000  |     1.00ms |     2.00ms",
+                " 2022 |  10 |    2 | #..#   |            |   500.00µs",
+                "      |     |      | .##.   |            |",
+                "Total time: 3.50ms",
+            ]
+            .join("\n"),
+            table.to_string()
+        );
+    }
+}
@@ -0,0 +1,330 @@
+//! Creation of a new day from the templates of `base/`.
+//!
+//! Every `*.tpl` file of `base/` is copied into `<year>/dayNN/` without its
+//! extension, replacing `{{crate}}`, `{{lib}}`, `{{solution}}`, `{{day}}` and
+//! `{{year}}`. The day is then registered in the workspace and in the runner.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Templates, relative to the workspace root.
+pub const TEMPLATES: &str = "base";
+
+/// Values of the placeholders of a template.
+pub struct Context {
+    pub day: u8,
+    pub year: u16,
+}
+
+impl Context {
+    /// Crate of the day, relative to the workspace root.
+    pub fn dir(&self) -> String {
+        format!("{}/day{:02}", self.year, self.day)
+    }
+
+    /// Package name, unique across years.
+    pub fn crate_name(&self) -> String {
+        format!("aoc{}-day{:02}", self.year, self.day)
+    }
+
+    /// Name of the library in `use` paths.
+    pub fn lib_name(&self) -> String {
+        self.crate_name().replace('-', "_")
+    }
+
+    pub fn solution(&self) -> String {
+        format!("Day{:02}", self.day)
+    }
+
+    fn value(&self, name: &str) -> Option<String> {
+        match name {
+            "crate" => Some(self.crate_name()),
+            "lib" => Some(self.lib_name()),
+            "solution" => Some(self.solution()),
+            "day" => Some(self.day.to_string()),
+            "year" => Some(self.year.to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// Replaces every `{{name}}` of `template`, failing on unknown names.
+pub fn render(template: &str, context: &Context) -> Result<String, String> {
+    let mut res = String::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        let end = rest[start..]
+            .find("}}")
+            .ok_or("unclosed `{{` in template")?;
+        let name = &rest[start + 2..start + end];
+        let value = context
+            .value(name.trim())
+            .ok_or(format!("unknown placeholder `{{{{{}}}}}`", name))?;
+
+        res += &rest[..start];
+        res += &value;
+        rest = &rest[start + end + 2..];
+    }
+    res += rest;
+
+    Ok(res)
+}
+
+/// Every `*.tpl` file under `dir`, relative to it and sorted.
+fn templates(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut res = vec![];
+    let mut pending = vec![PathBuf::new()];
+
+    while let Some(relative) = pending.pop() {
+        let entries = fs::read_dir(dir.join(&relative))
+            .map_err(|err| format!("cannot list {}: {}", dir.join(&relative).display(), err))?;
+        for entry in entries.filter_map(|it| it.ok()) {
+            let path = relative.join(entry.file_name());
+            if entry.path().is_dir() {
+                pending.push(path);
+            } else if path.extension().is_some_and(|it| it == "tpl") {
+                res.push(path);
+            }
+        }
+    }
+    res.sort();
+
+    Ok(res)
+}
+
+/// Year and day of the crate named by `line`, written `<year>-dayNN`,
+/// `<year>/dayNN` or `<year>_dayNN`, used to keep registrations sorted.
+fn crate_key(line: &str) -> Option<(u16, u8)> {
+    let digits = |text: &str| text.chars().all(|it| it.is_ascii_digit());
+    line.match_indices("day").find_map(|(idx, _)| {
+        let year = line.get(idx.checked_sub(5)?..idx - 1)?;
+        let day = line.get(idx + 3..idx + 5)?;
+        if !digits(year) || !digits(day) {
+            return None;
+        }
+        Some((year.parse().ok()?, day.parse().ok()?))
+    })
+}
+
+/// Inserts `added` among the crates listed on lines starting with `prefix`,
+/// in year then day order.
+fn register(text: &str, prefix: &str, added: &str) -> Result<String, String> {
+    let key = crate_key(added).ok_or(format!("no crate named in `{}`", added.trim()))?;
+    let mut lines = text.lines().collect::<Vec<_>>();
+
+    let similar = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with(prefix))
+        .filter_map(|(idx, line)| Some((idx, crate_key(line)?)))
+        .collect::<Vec<_>>();
+    if similar.iter().any(|(_, it)| *it == key) {
+        return Err(format!("{}/day{:02} is already registered", key.0, key.1));
+    }
+
+    let position = match similar.iter().rev().find(|(_, it)| *it < key) {
+        Some((idx, _)) => idx + 1,
+        None => {
+            similar
+                .first()
+                .ok_or(format!(
+                    "cannot find crates on lines starting with `{}`",
+                    prefix.trim()
+                ))?
+                .0
+        }
+    };
+    lines.insert(position, added);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Files to change for the new day, with their new content.
+fn changes(root: &Path, context: &Context) -> Result<Vec<(PathBuf, String)>, String> {
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|err| format!("cannot read {}: {}", path.display(), err))
+    };
+    let name = context.crate_name();
+    let path = context.dir();
+    let dir = root.join(&path);
+    let mut res = vec![];
+
+    let templates_dir = root.join(TEMPLATES);
+    for template in templates(&templates_dir)? {
+        let content = render(&read(&templates_dir.join(&template))?, context)?;
+        res.push((dir.join(template.with_extension("")), content));
+    }
+
+    let workspace = root.join("Cargo.toml");
+    res.push((
+        workspace.clone(),
+        register(&read(&workspace)?, "    \"", &format!("    \"{}\",", path))?,
+    ));
+
+    let runner = root.join("runner/Cargo.toml");
+    res.push((
+        runner.clone(),
+        register(
+            &read(&runner)?,
+            "aoc",
+            &format!("{} = {{ path = \"../{}\" }}", name, path),
+        )?,
+    ));
+
+    let days = root.join("runner/src/days.rs");
+    res.push((
+        days.clone(),
+        register(
+            &read(&days)?,
+            "    day!(",
+            &format!(
+                "    day!({}, {}, {}::{}),",
+                context.year,
+                context.day,
+                context.lib_name(),
+                context.solution()
+            ),
+        )?,
+    ));
+
+    Ok(res)
+}
+
+/// Creates `<year>/dayNN` and registers it, refusing to touch an existing day.
+///
+/// Everything is checked before the first file is written, so a failure
+/// leaves the workspace untouched.
+pub fn new_day(root: &Path, context: &Context) -> Result<Vec<PathBuf>, String> {
+    let dir = root.join(context.dir());
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let changes = changes(root, context)?;
+    for (path, content) in changes.iter() {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|err| format!("cannot create {}: {}", parent.display(), err))?;
+        }
+        fs::write(path, content)
+            .map_err(|err| format!("cannot write {}: {}", path.display(), err))?;
+    }
+
+    Ok(changes.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+
+    use crate::{
+        scaffold::{crate_key, new_day, register, render, Context, TEMPLATES},
+        stand_in::temp_dir,
+    };
+
+    const CONTEXT: Context = Context {
+        day: 13,
+        year: 2022,
+    };
+
+    #[test]
+    fn placeholders() {
+        assert_eq!(
+            Ok("use aoc2022_day13::Day13; // aoc2022-day13 13 2022".into()),
+            render(
+                "use {{lib}}::{{solution}}; // {{crate}} {{day}} {{ year }}",
+                &CONTEXT
+            )
+        );
+        assert_eq!(
+            Err("unknown placeholder `{{days}}`".into()),
+            render("{{days}}", &CONTEXT)
+        );
+        assert!(render("{{crate", &CONTEXT).is_err());
+    }
+
+    #[test]
+    fn keys() {
+        assert_eq!(Some((2022, 9)), crate_key("    \"2022/day09\","));
+        assert_eq!(
+            Some((2015, 25)),
+            crate_key("aoc2015-day25 = { path = \"../2015/day25\" }")
+        );
+        assert_eq!(
+            Some((2022, 1)),
+            crate_key("    day!(2022, 1, aoc2022_day01::Day01),")
+        );
+        assert_eq!(None, crate_key("    \"common\","));
+    }
+
+    #[test]
+    fn registration() {
+        let days = "pub const DAYS: &[Day] = &[\n    day!(2021, 10, aoc2021_day10::Day10),\n    day!(2022, 9, aoc2022_day09::Day09),\n    day!(2022, 12, aoc2022_day12::Day12),\n];\n";
+        assert_eq!(
+            "pub const DAYS: &[Day] = &[\n    day!(2021, 10, aoc2021_day10::Day10),\n    day!(2022, 9, aoc2022_day09::Day09),\n    day!(2022, 10, aoc2022_day10::Day10),\n    day!(2022, 12, aoc2022_day12::Day12),\n];\n",
+            register(days, "    day!(", "    day!(2022, 10, aoc2022_day10::Day10),").unwrap()
+        );
+        assert_eq!(
+            Err("2022/day09 is already registered".into()),
+            register(
+                days,
+                "    day!(",
+                "    day!(2022, 9, aoc2022_day09::Day09),"
+            )
+        );
+    }
+
+    #[test]
+    fn new_crate() {
+        let repo = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let root = temp_dir("scaffold");
+        let workspace = [
+            (
+                "Cargo.toml",
+                "[workspace]\nmembers = [\n    \"common\",\n    \"2022/day01\",\n    \"2022/day12\",\n    \"runner\",\n]\n",
+            ),
+            (
+                "runner/Cargo.toml",
+                "[dependencies]\naoc-common = { path = \"../common\" }\nclap = \"4\"\naoc2022-day01 = { path = \"../2022/day01\" }\naoc2022-day12 = { path = \"../2022/day12\" }\nserde = \"1\"\n",
+            ),
+            (
+                "runner/src/days.rs",
+                "pub const DAYS: &[Day] = &[\n    day!(2022, 1, aoc2022_day01::Day01),\n    day!(2022, 12, aoc2022_day12::Day12),\n];\n",
+            ),
+        ];
+        for (file, content) in workspace {
+            fs::create_dir_all(root.join(file).parent().unwrap()).unwrap();
+            fs::write(root.join(file), content).unwrap();
+        }
+        for template in ["src/lib.rs.tpl", "src/main.rs.tpl", "Cargo.toml.tpl"] {
+            let path = Path::new(TEMPLATES).join(template);
+            fs::create_dir_all(root.join(&path).parent().unwrap()).unwrap();
+            fs::copy(repo.join(&path), root.join(&path)).unwrap();
+        }
+
+        new_day(&root, &CONTEXT).unwrap();
+        new_day(&root, &Context { day: 1, year: 2023 }).unwrap();
+
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+        assert!(read("2022/day13/src/lib.rs").contains("pub struct Day13;"));
+        assert!(read("2022/day13/src/main.rs").contains("use aoc2022_day13::Day13;"));
+        assert!(read("2022/day13/Cargo.toml").contains("name = \"aoc2022-day13\""));
+        assert!(read("Cargo.toml").contains(
+            "    \"2022/day12\",\n    \"2022/day13\",\n    \"2023/day01\",\n    \"runner\","
+        ));
+        assert!(read("runner/Cargo.toml").contains(
+            "aoc2022-day12 = { path = \"../2022/day12\" }\naoc2022-day13 = { path = \"../2022/day13\" }\naoc2023-day01 = { path = \"../2023/day01\" }\nserde"
+        ));
+        assert!(read("runner/src/days.rs").contains(
+            "    day!(2022, 13, aoc2022_day13::Day13),\n    day!(2023, 1, aoc2023_day01::Day01),\n];"
+        ));
+
+        // A second run must not overwrite the day
+        fs::write(root.join("2022/day13/src/lib.rs"), "// solved").unwrap();
+        assert!(new_day(&root, &CONTEXT).is_err());
+        assert_eq!("// solved", read("2022/day13/src/lib.rs"));
+    }
+}
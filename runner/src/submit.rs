@@ -0,0 +1,237 @@
+//! Answer submission, remembering every answer sent so that known-wrong ones
+//! are refused locally instead of costing a penalty.
+
+use std::{collections::BTreeMap, fmt, fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+
+/// Environment variable overriding where the history is stored.
+pub const HISTORY_VAR: &str = "AOC_HISTORY";
+/// Default history, relative to the workspace root.
+pub const DEFAULT_HISTORY: &str = ".aoc/history.json";
+
+/// What the server said about an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Correct => "the right answer",
+                Self::Wrong => "not the right answer",
+                Self::TooHigh => "too high",
+                Self::TooLow => "too low",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch
+    pub at: u64,
+}
+
+/// Every submission, by `<year>/dayNN/partN`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct History(pub BTreeMap<String, Vec<Submission>>);
+
+fn key(year: u16, day: u8, part: u8) -> String {
+    format!("{}/day{:02}/part{}", year, day, part)
+}
+
+impl History {
+    /// History stored at `path`, empty when there is none yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(raw) => serde_json::from_str(&raw)
+                .map_err(|err| format!("invalid history {}: {}", path.display(), err)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("cannot read {}: {}", path.display(), err)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let write = || -> std::io::Result<()> {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(path, serde_json::to_string_pretty(self)?)
+        };
+        write().map_err(|err| format!("cannot save {}: {}", path.display(), err))
+    }
+
+    pub fn submissions(&self, year: u16, day: u8, part: u8) -> &[Submission] {
+        self.0
+            .get(&key(year, day, part))
+            .map_or(&[], |it| it.as_slice())
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, part: u8, answer: &str, verdict: Verdict) {
+        let at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |it| it.as_secs());
+        self.0
+            .entry(key(year, day, part))
+            .or_default()
+            .push(Submission {
+                answer: answer.into(),
+                verdict,
+                at,
+            });
+    }
+
+    /// Explains why `answer` cannot be right, judging from the previous
+    /// submissions.
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<(), String> {
+        let submissions = self.submissions(year, day, part);
+
+        if let Some(solved) = submissions.iter().find(|it| it.verdict == Verdict::Correct) {
+            return Err(format!("already solved, the answer was {}", solved.answer));
+        }
+        if let Some(known) = submissions.iter().find(|it| it.answer == answer) {
+            return Err(format!(
+                "{} was already submitted: {}",
+                answer, known.verdict
+            ));
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+        let bound = |verdict| {
+            submissions
+                .iter()
+                .filter(move |it| it.verdict == verdict)
+                .filter_map(|it| it.answer.parse::<i128>().ok())
+        };
+        if let Some(high) = bound(Verdict::TooHigh).min().filter(|it| value >= *it) {
+            return Err(format!("{} is too high, {} already was", answer, high));
+        }
+        if let Some(low) = bound(Verdict::TooLow).max().filter(|it| value <= *it) {
+            return Err(format!("{} is too low, {} already was", answer, low));
+        }
+
+        Ok(())
+    }
+}
+
+/// Sends `answer` unless the history proves it wrong, and records the verdict.
+pub fn submit(
+    history: &mut History,
+    client: &Client,
+    year: u16,
+    (day, part): (u8, u8),
+    answer: &str,
+) -> Result<Verdict, String> {
+    history.check(year, day, part, answer)?;
+    let verdict = client.submit(year, day, part, answer)?;
+    history.record(year, day, part, answer, verdict);
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        client::{Client, Throttle},
+        stand_in::{self, temp_dir},
+        submit::{submit, History, Verdict},
+    };
+
+    #[test]
+    fn refusals() {
+        let mut history = History::default();
+        history.record(2022, 1, 1, "500", Verdict::TooHigh);
+        history.record(2022, 1, 1, "100", Verdict::TooLow);
+        history.record(2022, 1, 1, "300", Verdict::Wrong);
+        history.record(2022, 1, 1, "abc", Verdict::Wrong);
+
+        assert_eq!(Ok(()), history.check(2022, 1, 1, "200"));
+        assert_eq!(Ok(()), history.check(2022, 1, 1, "xyz"));
+        assert_eq!(Ok(()), history.check(2022, 1, 2, "300"));
+        assert_eq!(
+            Err("300 was already submitted: not the right answer".into()),
+            history.check(2022, 1, 1, "300")
+        );
+        assert_eq!(
+            Err("600 is too high, 500 already was".into()),
+            history.check(2022, 1, 1, "600")
+        );
+        assert_eq!(
+            Err("50 is too low, 100 already was".into()),
+            history.check(2022, 1, 1, "50")
+        );
+
+        history.record(2022, 1, 1, "250", Verdict::Correct);
+        assert_eq!(
+            Err("already solved, the answer was 250".into()),
+            history.check(2022, 1, 1, "200")
+        );
+    }
+
+    #[test]
+    fn persistence() {
+        let path = temp_dir("history").join("history.json");
+        assert_eq!(History::default(), History::load(&path).unwrap());
+
+        let mut history = History::default();
+        history.record(2022, 5, 2, "MCD", Verdict::Correct);
+        history.save(&path).unwrap();
+
+        let loaded = History::load(&path).unwrap();
+        assert_eq!(history, loaded);
+        assert_eq!("MCD", loaded.submissions(2022, 5, 2)[0].answer);
+    }
+
+    #[test]
+    fn workflow() {
+        let server = stand_in::serve(vec![
+            (
+                200,
+                "<article><p>That's not the right answer; your answer is too low.</p></article>",
+            ),
+            (
+                200,
+                "<article><p>That's the right answer! You are one gold star closer.</p></article>",
+            ),
+        ]);
+        let throttle = Throttle {
+            stamp: temp_dir("workflow").join(".last-request"),
+            interval: Duration::ZERO,
+        };
+        let client = Client::new(&server.url, "secret", throttle);
+        let mut history = History::default();
+
+        assert_eq!(
+            Ok(Verdict::TooLow),
+            submit(&mut history, &client, 2022, (1, 1), "10")
+        );
+        // Refused locally, the stand-in is not called
+        assert!(submit(&mut history, &client, 2022, (1, 1), "8").is_err());
+        assert_eq!(
+            Ok(Verdict::Correct),
+            submit(&mut history, &client, 2022, (1, 1), "24000")
+        );
+        assert_eq!(2, history.submissions(2022, 1, 1).len());
+
+        let requests = server.finish();
+        assert!(requests[0].starts_with("POST /2022/day/1/answer HTTP/1.1\r\n"));
+        assert!(requests[1].ends_with("\r\n\r\nlevel=1&answer=24000"));
+    }
+}